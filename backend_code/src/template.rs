@@ -14,6 +14,7 @@ use reqwest::Client as HttpClient;
 #[allow(unused_imports)]
 use async_trait::async_trait;
 use std::collections::HashMap;
+use std::env;
 use std::fs;
 use std::io::Write;
 use std::sync::Mutex;
@@ -230,6 +231,12 @@ async fn login(
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
+    let host = env::var("BACKEND_HOST").unwrap_or("127.0.0.1".to_string());
+    let port: u16 = env::var("BACKEND_PORT")
+        .ok()
+        .and_then(|port| port.parse().ok())
+        .unwrap_or(8080);
+
     let database = match Database::load_from_file() {
         Ok(database) => database,
         Err(_) => Database::new(),
@@ -266,7 +273,7 @@ async fn main() -> std::io::Result<()> {
             .route("/register", web::post().to(register))
             .route("/login", web::post().to(login))
     })
-    .bind(("127.0.0.1", 8080))?
+    .bind((host, port))?
     .run()
     .await
 }
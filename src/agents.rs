@@ -9,5 +9,9 @@
 
 pub mod architect;
 pub mod backend;
+pub mod frontend;
 pub mod manager;
+pub mod planner;
+pub mod qa;
+pub mod reviewer;
 pub mod common;
\ No newline at end of file
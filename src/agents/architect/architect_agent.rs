@@ -9,11 +9,12 @@
 
 use super::architect_ai_functions::{ print_project_scope, print_site_urls };
 use super::super::common::attributes::{ AgentAttributes, AgentStatus };
+use super::super::common::report::AgentReport;
 use super::super::common::traits::Agent;
 
-use crate::model::{ project::Project, project_scope::ProjectScope };
+use crate::model::{ project::Project, project_field::ProjectField, project_scope::ProjectScope };
 use crate::utilities::ai_tasks::ai_task_request_decoded;
-use crate::utilities::command_line::CommandLine;
+use crate::utilities::event_bus::{ AgentEvent, EventBus };
 use crate::utilities::networking::check_status_code;
 
 use async_trait::async_trait;
@@ -34,48 +35,56 @@ impl SolutionArchitectAgent {
             status: AgentStatus::Discovery,
         };
         Self {
-            attributes: attributes
+            attributes
         }
     }
 
-    async fn determine_project_scope(&mut self, project: &mut Project) -> ProjectScope {
-        let message_context = format!("{}", project.description);
+    async fn determine_project_scope(&mut self, project: &mut Project) -> Result<ProjectScope, String> {
+        let message_context = project.description.clone();
         let ai_response: ProjectScope = ai_task_request_decoded(
             message_context, 
             &self.attributes.position, 
             get_function_string!(print_project_scope), 
             print_project_scope,
-        ).await;
+        ).await?;
 
-        project.scope = Some(ai_response.clone());
-        self.attributes.status = AgentStatus::Finished;
-        return ai_response;
+        project.scope = Some(ai_response);
+        self.attributes.set_status(AgentStatus::Finished);
+        Ok(ai_response)
     }
 
-    async fn determine_external_urls(&mut self, project: &mut Project, message_context: String) {
+    async fn determine_external_urls(&mut self, project: &mut Project, message_context: String) -> Result<(), String> {
         let ai_response: Vec<String> = ai_task_request_decoded(
             message_context, 
             &self.attributes.position, 
             get_function_string!(print_site_urls), 
             print_site_urls,
-        ).await;
+        ).await?;
 
         project.external_urls = Some(ai_response);
-        self.attributes.status = AgentStatus::UnitTesting;
+        self.attributes.set_status(AgentStatus::UnitTesting);
+        Ok(())
+    }
+}
+
+impl Default for SolutionArchitectAgent {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 #[async_trait]
 impl Agent for SolutionArchitectAgent {
-    async fn execute(&mut self, project: &mut Project) -> Result<(), Box<dyn std::error::Error>> {
+    async fn execute(&mut self, project: &mut Project) -> Result<AgentReport, Box<dyn std::error::Error>> {
+        let mut report = AgentReport::new(&self.attributes.position);
         while self.attributes.status != AgentStatus::Finished {
             match self.attributes.status {
 
                 AgentStatus::Discovery => {
-                    let project_scope = self.determine_project_scope(project).await;
+                    let project_scope = self.determine_project_scope(project).await?;
                     if project_scope.is_external_urls_required {
-                        self.determine_external_urls(project, project.description.clone()).await;
-                        self.attributes.status = AgentStatus::UnitTesting;
+                        self.determine_external_urls(project, project.description.clone()).await?;
+                        self.attributes.set_status(AgentStatus::UnitTesting);
                     }
                 },
 
@@ -93,7 +102,7 @@ impl Agent for SolutionArchitectAgent {
 
                     for url in urls {
                         let endpoint_string = format!("Testing URL endpoint: {}", url);
-                        CommandLine::print_agent_unit_test_message(
+                        EventBus::publish_unit_test_message(
                             &self.attributes.position, 
                             endpoint_string.as_str(), 
                         );
@@ -101,41 +110,85 @@ impl Agent for SolutionArchitectAgent {
                         // Perform URL test.
                         match check_status_code(&client, url).await {
                             Ok(status_code) => {
+                                EventBus::publish(AgentEvent::ExternalUrlChecked {
+                                    position: self.attributes.position.clone(),
+                                    url: url.clone(),
+                                    status_code: Some(status_code),
+                                    excluded: status_code != 200,
+                                });
                                 if status_code != 200 {
+                                    report.add_warning(format!("Excluded {}, which returned status {}.", url, status_code));
                                     exclude_urls.push(url.clone());
                                 }
                             },
                             Err(error) => {
-                                println!("Error checking {}: {}", url, error);
+                                tracing::warn!(url, %error, "failed to check external URL");
+                                EventBus::publish(AgentEvent::ExternalUrlChecked {
+                                    position: self.attributes.position.clone(),
+                                    url: url.clone(),
+                                    status_code: None,
+                                    excluded: false,
+                                });
+                                report.add_warning(format!("Could not check {}: {}", url, error));
                             },
                         }
                     }
 
                     // Exclude any faulty URLs.
-                    if exclude_urls.len() > 0 {
+                    if !exclude_urls.is_empty() {
                         let new_urls = project
                             .external_urls
                             .as_ref()
                             .unwrap()
                             .iter()
-                            .filter(|url| !exclude_urls.contains(&url))
+                            .filter(|url| !exclude_urls.contains(url))
                             .cloned()
                             .collect();
                         project.external_urls = Some(new_urls);
                     }
 
                     // Confirm done.
-                    self.attributes.status = AgentStatus::Finished;
+                    self.attributes.set_status(AgentStatus::Finished);
                 },
 
                 // Default to finished status.
                 _ => {
-                    self.attributes.status = AgentStatus::Finished;
+                    self.attributes.set_status(AgentStatus::Finished);
                 }
             }
         }
 
-        Ok(())
+        if let Some(external_urls) = &project.external_urls {
+            report.add_artifact(format!("{} external URLs", external_urls.len()));
+        }
+        report.add_artifact("Project scope".to_string());
+
+        Ok(report)
+    }
+
+    fn attributes(&self) -> &AgentAttributes {
+        &self.attributes
+    }
+
+    fn can_run(&self, project: &Project) -> bool {
+        !project.description.is_empty()
+    }
+
+    fn reset(&mut self) {
+        self.attributes.set_status(AgentStatus::Discovery);
+    }
+
+    fn requires(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::Description,
+        ]
+    }
+
+    fn produces(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::Scope,
+            ProjectField::ExternalUrls,
+        ]
     }
 }
 
@@ -152,10 +205,16 @@ mod tests {
         let mut agent = SolutionArchitectAgent::new();
         let mut project = Project {
             description: "Build a full stack website with user login and logout that shows latest Forex prices".to_string(),
+            requirements: None,
             scope: None,
             external_urls: None,
             backend_code: None,
+            backend_tasks: vec![],
             api_endpoint_schema: None,
+            server_address: None,
+            frontend_code: None,
+            review_issues: vec![],
+            defects: vec![],
         };
         agent.execute(&mut project)
             .await
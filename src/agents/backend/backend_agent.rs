@@ -12,26 +12,55 @@ use super::backend_ai_functions::{
     print_improved_webserver_code, print_rest_api_endpoints, 
 };
 use super::super::common::attributes::{ AgentAttributes, AgentStatus };
+use super::super::common::report::AgentReport;
 use super::super::common::traits::Agent;
+use super::super::reviewer::code_review_agent::CodeReviewAgent;
 
-use crate::model::endpoint_route::{ EndpointRoute, HTTPMethod };
+use crate::model::backend_task::{ backend_task_progress, BackendTaskStatus };
+use crate::model::endpoint_route::EndpointRoute;
 use crate::model::project::Project;
-use crate::utilities::ai_tasks::ai_task_request;
+use crate::model::project_field::ProjectField;
+use crate::model::server_address::ServerAddress;
+use crate::utilities::ai_tasks::{ ai_task_request, ai_task_request_decoded };
 use crate::utilities::backend_code_persistence::BackendCodePersistence;
+use crate::utilities::backend_server::BackendServer;
 use crate::utilities::command_line::CommandLine;
-use crate::utilities::networking::check_status_code;
+use crate::utilities::endpoint_testing::{
+    plan_endpoint_tests, run_endpoint_tests, 
+    EndpointTestResult, EndpointTestStep, 
+};
+use crate::utilities::event_bus::{ AgentEvent, EventBus };
+use crate::utilities::integration_test_generator::integration_test_suite;
+use crate::utilities::networking::{ find_available_port, is_port_available };
+use crate::utilities::open_api::{ open_api_document, validate_open_api_document };
+use crate::utilities::rest_api_analyzer::analyze_rest_api_endpoints;
+use crate::utilities::rust_client_generator::{ rust_client_library, rust_client_manifest };
+use crate::utilities::typescript_client_generator::typescript_client_module;
 
 use async_trait::async_trait;
 use reqwest::Client;
 use std::process::{ Command, Stdio };
 use std::time::Duration;
-use tokio::time;
+
+const DEFAULT_SERVER_HOST: &str = "127.0.0.1";
+const DEFAULT_SERVER_STARTUP_DEADLINE: Duration = Duration::from_secs(30);
+const DEFAULT_MAXIMUM_BUG_COUNT: u8 = 10;
+const DEFAULT_MAXIMUM_REVIEW_ROUNDS: u8 = 2;
 
 #[derive(Debug)]
 pub struct BackendDeveloperAgent {
     pub attributes: AgentAttributes,
     pub bug_errors: Option<String>,
     pub bug_count: u8,
+    pub maximum_bug_count: u8,
+    // Review rounds spent on the current task.
+    pub review_rounds: u8,
+    pub maximum_review_rounds: u8,
+    // Whether the code was reviewed since it last changed.
+    pub is_code_reviewed: bool,
+    pub server_startup_deadline: Duration,
+    pub server_host: String,
+    pub server_port: Option<u16>,
 }
 
 impl BackendDeveloperAgent {
@@ -43,19 +72,71 @@ impl BackendDeveloperAgent {
         };
 
         Self {
-            attributes: attributes,
+            attributes,
             bug_errors: None,
             bug_count: 0,
+            maximum_bug_count: DEFAULT_MAXIMUM_BUG_COUNT,
+            review_rounds: 0,
+            maximum_review_rounds: DEFAULT_MAXIMUM_REVIEW_ROUNDS,
+            is_code_reviewed: false,
+            server_startup_deadline: DEFAULT_SERVER_STARTUP_DEADLINE,
+            server_host: DEFAULT_SERVER_HOST.to_string(),
+            server_port: None,
+        }
+    }
+
+    // Determine the port for the test server, picking a free one unless configured.
+    fn select_server_port(&self) -> Result<u16, String> {
+        match self.server_port {
+            Some(port) if is_port_available(&self.server_host, port) => Ok(port),
+            Some(port) => Err(format!(
+                "Port {} on {} is already in use by another process.", 
+                port, self.server_host, 
+            )),
+            None => find_available_port(&self.server_host)
+                .map_err(|error| format!("Unable to find a free port: {}", error)),
+        }
+    }
+
+    fn server_address(&self, server_port: u16) -> ServerAddress {
+        ServerAddress {
+            host: self.server_host.clone(),
+            port: server_port,
+        }
+    }
+
+    // Record a bug to be fixed in the next working iteration, failing once there are too many.
+    fn record_bug(&mut self, error_string: String) -> Result<(), String> {
+        self.bug_count = self.bug_count.saturating_add(1);
+        EventBus::publish(AgentEvent::BugRecorded {
+            position: self.attributes.position.clone(),
+            iteration: self.bug_count,
+            diagnostics: error_string.clone(),
+        });
+        self.bug_errors = Some(error_string);
+
+        // Exit if too many bugs.
+        if self.bug_count > self.maximum_bug_count {
+            EventBus::publish_issue_message(
+                &self.attributes.position, 
+                "Backend Code Unit Testing: Too many bugs found in code.", 
+            );
+            return Err(format!("Too many bugs found in code, after {} attempts to fix them.", self.maximum_bug_count));
         }
+        Ok(())
     }
 
     async fn generate_initial_backend_code(&mut self, project: &mut Project) {
         let code_template_string = BackendCodePersistence::read_code_template_contents();
 
         // Concatenate instructions
+        let backend_task_summaries: Vec<String> = project.backend_tasks
+            .iter()
+            .map(|backend_task| backend_task.summary())
+            .collect();
         let message_context = format!(
-            "CODE_TEMPLATE: {} \n PROJECT_DESCRIPTION: {} \n",
-            code_template_string, project.description, 
+            "CODE_TEMPLATE: {} \n PROJECT_DESCRIPTION: {} \n BACKEND_TASKS: {:?} \n",
+            code_template_string, project.description, backend_task_summaries, 
         );
 
         let backend_code: String = ai_task_request(
@@ -70,9 +151,22 @@ impl BackendDeveloperAgent {
     }
 
     async fn improve_backend_code(&mut self, project: &mut Project) {
+
+        // Work on the next planned task, if there is one.
+        let current_backend_task = project.backend_tasks
+            .iter_mut()
+            .find(|backend_task| backend_task.status != BackendTaskStatus::Done);
+        let current_backend_task_summary = match current_backend_task {
+            Some(backend_task) => {
+                backend_task.status = BackendTaskStatus::InProgress;
+                backend_task.summary()
+            },
+            None => "None".to_string(),
+        };
+
         let message_context = format!(
-            "CODE_TEMPLATE: {:?} \n PROJECT_DESCRIPTION: {:?} \n",
-            project.backend_code, project, 
+            "CODE_TEMPLATE: {:?} \n PROJECT_DESCRIPTION: {:?} \n CURRENT_TASK: {} \n",
+            project.backend_code, project, current_backend_task_summary, 
         );
 
         let backend_code: String = ai_task_request(
@@ -104,21 +198,352 @@ impl BackendDeveloperAgent {
         project.backend_code = Some(backend_code);
     }
 
-    async fn extract_rest_api_endpoints(&self) -> String {
+    // Write the endpoint schema as a validated OpenAPI document next to the generated code.
+    fn export_open_api_document(&self, project: &Project) {
+        let endpoints = project.api_endpoint_schema.as_deref().unwrap_or_default();
+        let document = open_api_document(&project.description, endpoints);
+        let validation_errors = validate_open_api_document(&document);
+        if !validation_errors.is_empty() {
+            let error_message = format!(
+                "Backend Code Unit Testing: Generated OpenAPI document is invalid: {}", 
+                validation_errors.join(" "), 
+            );
+            EventBus::publish_issue_message(&self.attributes.position, error_message.as_str());
+            return;
+        }
+
+        let document_string = serde_json::to_string_pretty(&document)
+            .expect("Failed to encode OpenAPI document.");
+        BackendCodePersistence::save_open_api_document(&document_string);
+    }
+
+    // Write the integration test suite into the generated project and run it with `cargo test`.
+    fn run_integration_tests(&self, project: &Project) -> Result<(), String> {
+        let endpoints = project.api_endpoint_schema.as_deref().unwrap_or_default();
+        BackendCodePersistence::save_integration_tests(&integration_test_suite(
+            endpoints, 
+            &BackendCodePersistence::read_backend_code_package_name(), 
+        ));
+
+        EventBus::publish_unit_test_message(
+            &self.attributes.position, 
+            "Backend Code Unit Testing: running integration tests…", 
+        );
+        let test_backend_server = Command::new("cargo")
+            .arg("test")
+            .current_dir(BackendCodePersistence::get_backend_code_project_path())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .map_err(|error| format!("Unable to run `cargo test`: {}", error))?;
+        if test_backend_server.status.success() {
+            return Ok(());
+        }
+
+        // Keep the failure report, which follows the list of passing tests.
+        let test_output = String::from_utf8_lossy(&test_backend_server.stdout);
+        let failure_report = match test_output.find("failures:") {
+            Some(failures_index) => test_output[failures_index..].to_string(),
+            None => format!("{}\n{}", test_output, String::from_utf8_lossy(&test_backend_server.stderr)),
+        };
+        Err(failure_report)
+    }
+
+    // Generate the typed Rust client crate next to the generated code, and make sure it compiles.
+    fn generate_rust_client(&self, project: &Project, report: &mut AgentReport) {
+        let endpoints = project.api_endpoint_schema.as_deref().unwrap_or_default();
+        BackendCodePersistence::save_backend_client(&rust_client_manifest(), &rust_client_library(endpoints));
+
+        let build_backend_client = match Command::new("cargo")
+            .arg("build")
+            .current_dir(BackendCodePersistence::get_backend_client_project_path())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+        {
+            Ok(build_backend_client) => build_backend_client,
+            Err(error) => {
+                let error_message = format!("Unable to run `cargo build` for the generated Rust client: {}", error);
+                EventBus::publish_issue_message(&self.attributes.position, error_message.as_str());
+                report.add_failed_check(error_message);
+                return;
+            },
+        };
+        EventBus::publish(AgentEvent::BuildFinished {
+            position: self.attributes.position.clone(),
+            target: "Rust client".to_string(),
+            succeeded: build_backend_client.status.success(),
+        });
+        if build_backend_client.status.success() {
+            EventBus::publish_unit_test_message(
+                &self.attributes.position, 
+                "Backend Code Unit Testing: Rust client build successful!", 
+            );
+            report.add_artifact("Rust client crate".to_string());
+        } else {
+            let error_message = format!(
+                "Backend Code Unit Testing: Generated Rust client failed to build: {}", 
+                String::from_utf8_lossy(&build_backend_client.stderr), 
+            );
+            EventBus::publish_issue_message(&self.attributes.position, error_message.as_str());
+            report.add_failed_check("Generated Rust client failed to build.".to_string());
+        }
+    }
+
+    // Print a pass or fail line for each endpoint, followed by a summary.
+    fn report_endpoint_test_results(&self, endpoint_test_results: &[EndpointTestResult]) {
+        for result in endpoint_test_results {
+            EventBus::publish(AgentEvent::EndpointTested {
+                position: self.attributes.position.clone(),
+                method: result.method.clone(),
+                route: result.route.clone(),
+                passed: result.passed,
+                detail: result.detail.clone(),
+            });
+        }
+
+        let passed_count = endpoint_test_results.iter().filter(|result| result.passed).count();
+        let summary_message = format!(
+            "Backend Code Unit Testing: {}/{} endpoint tests passed.", 
+            passed_count, endpoint_test_results.len(), 
+        );
+        EventBus::publish_unit_test_message(&self.attributes.position, summary_message.as_str());
+    }
+
+    async fn extract_rest_api_endpoints(&self) -> Result<Vec<EndpointRoute>, String> {
         let backend_code = BackendCodePersistence::read_executable_main_contents();
 
+        // Derive endpoints from the code itself whenever the analyzer understands it.
+        match analyze_rest_api_endpoints(&backend_code) {
+            Ok(analysis) if analysis.is_complete() => {
+                EventBus::publish_unit_test_message(
+                    &self.attributes.position, 
+                    "Backend Code Unit Testing: Extracted API endpoints from code.", 
+                );
+                return Ok(analysis.endpoints);
+            },
+            Ok(analysis) => {
+                let fallback_message = format!(
+                    "Backend Code Unit Testing: Falling back to LLM endpoint extraction. {}", 
+                    analysis.unresolved.join(" "), 
+                );
+                EventBus::publish_issue_message(&self.attributes.position, fallback_message.as_str());
+            },
+            Err(error) => {
+                let fallback_message = format!(
+                    "Backend Code Unit Testing: Falling back to LLM endpoint extraction. Unable to parse code: {}", 
+                    error, 
+                );
+                EventBus::publish_issue_message(&self.attributes.position, fallback_message.as_str());
+            },
+        }
+
         // Structure message context.
         let message_context = format!("CODE_INPUT: {:?}", backend_code);
-        let rest_api_endpoints: String = ai_task_request(
+        ai_task_request_decoded(
             message_context, 
             &self.attributes.position, 
             get_function_string!(print_rest_api_endpoints), 
             print_rest_api_endpoints,
+        ).await
+    }
+
+    // Guard: ensure AI safety before running generated code.
+    fn confirm_safe_code(&self) -> Result<(), String> {
+        EventBus::publish_unit_test_message(
+            &self.attributes.position, 
+            "Backend Code Unit Testing: Requesting user input", 
+        );
+
+        let is_safe_code = CommandLine::confirm_safe_code();
+        if !is_safe_code {
+            return Err("The user declined to run the generated code; pass --yes to run it without asking.".to_string());
+        }
+        Ok(())
+    }
+
+    // Build the backend server, returning the compiler errors when it fails.
+    fn build_backend_server(&self) -> Result<(), String> {
+        EventBus::publish_unit_test_message(
+            &self.attributes.position, 
+            "Backend Code Unit Testing: building project…", 
+        );
+        let build_backend_server = Command::new("cargo")
+            .arg("build")
+            .current_dir(BackendCodePersistence::get_backend_code_project_path())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .output()
+            .map_err(|error| format!("Unable to run `cargo build`: {}", error))?;
+
+        // Determine if there are any build errors.
+        EventBus::publish(AgentEvent::BuildFinished {
+            position: self.attributes.position.clone(),
+            target: "backend server".to_string(),
+            succeeded: build_backend_server.status.success(),
+        });
+        if !build_backend_server.status.success() {
+            return Err(String::from_utf8(build_backend_server.stderr).unwrap_or_default());
+        }
+        EventBus::publish_unit_test_message(
+            &self.attributes.position, 
+            "Backend Code Unit Testing: Test server build successful!", 
+        );
+        Ok(())
+    }
+
+    // Start the built server and wait for it to accept connections, keeping its output when it does not.
+    async fn launch_backend_server(&self, server_port: u16) -> Result<BackendServer, String> {
+        EventBus::publish_unit_test_message(
+            &self.attributes.position, 
+            "Backend Code Unit Testing: Starting web server…", 
+        );
+
+        let mut run_backend_server = BackendServer::launch_backend_binary("backend", &self.server_host, server_port)
+            .map_err(|error| {
+                let error_message = format!("Backend Code Unit Testing: Unable to run the backend server. {}", error);
+                EventBus::publish_issue_message(&self.attributes.position, error_message.as_str());
+                format!("Unable to run the backend server binary: {}", error)
+            })?;
+
+        // Wait for the server to accept connections.
+        if let Err(error) = run_backend_server
+            .wait_until_ready(&self.server_host, server_port, self.server_startup_deadline)
+            .await
+        {
+            run_backend_server.stop();
+            BackendCodePersistence::save_server_log(&run_backend_server.captured_output());
+            let error_message = format!("Backend Code Unit Testing: Server failed to start. {}", error);
+            EventBus::publish_issue_message(
+                &self.attributes.position, 
+                error_message.as_str(), 
+            );
+            return Err(error.to_string());
+        }
+
+        // Let user know testing on server will start.
+        EventBus::publish_unit_test_message(
+            &self.attributes.position, 
+            "Backend Code Unit Testing: Server is ready, launching tests…", 
+        );
+        Ok(run_backend_server)
+    }
+
+    // Run the endpoint tests against a ready server, then stop it and keep its output.
+    async fn test_endpoints_on_server(
+        &self, 
+        run_backend_server: &mut BackendServer, 
+        server_port: u16, 
+        endpoint_test_steps: &[EndpointTestStep], 
+    ) -> Vec<EndpointTestResult> {
+
+        // Create client with timeout.
+        let client = Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()
+            .expect("Unable to instantiate reqwest client.");
+
+        let server_address = self.server_address(server_port);
+        let endpoint_test_results = run_endpoint_tests(&client, &server_address.base_url(), endpoint_test_steps).await;
+        self.report_endpoint_test_results(&endpoint_test_results);
+
+        run_backend_server.stop();
+        BackendCodePersistence::save_server_log(&run_backend_server.captured_output());
+        endpoint_test_results
+    }
+
+    // Build the code on disk, fixing it until it compiles, without the rest of the pipeline.
+    pub async fn fix_build(
+        &mut self, 
+        project: &mut Project
+    ) -> Result<AgentReport, Box<dyn std::error::Error>> {
+        let mut report = AgentReport::new(&self.attributes.position);
+        project.backend_code = Some(BackendCodePersistence::read_executable_main_contents());
+        self.attributes.set_status(AgentStatus::UnitTesting);
+        loop {
+            self.confirm_safe_code()?;
+            match self.build_backend_server() {
+                Ok(()) => break,
+                Err(error_string) => {
+                    self.record_bug(error_string)?;
+                    self.attributes.set_status(AgentStatus::Working);
+                    self.fix_code_bugs(project).await;
+                    self.attributes.set_status(AgentStatus::UnitTesting);
+                },
+            }
+        }
+
+        self.bug_errors = None;
+        report.add_artifact("Backend code".to_string());
+        if self.bug_count > 0 {
+            report.add_warning(format!("{} bugs were fixed before the code built.", self.bug_count));
+        }
+        self.attributes.set_status(AgentStatus::Finished);
+        Ok(report)
+    }
+
+    // Build the code on disk and test its endpoints on a running server, without fixing anything.
+    pub async fn test_endpoints(
+        &mut self, 
+        project: &mut Project
+    ) -> Result<AgentReport, Box<dyn std::error::Error>> {
+        let mut report = AgentReport::new(&self.attributes.position);
+        project.backend_code = Some(BackendCodePersistence::read_executable_main_contents());
+        self.attributes.set_status(AgentStatus::UnitTesting);
+        self.confirm_safe_code()?;
+        self.build_backend_server()
+            .map_err(|error_string| format!("The backend failed to build:\n{}", error_string))?;
+
+        let api_endpoints = self.extract_rest_api_endpoints().await?;
+        let endpoint_test_steps = plan_endpoint_tests(&api_endpoints);
+        project.api_endpoint_schema = Some(api_endpoints);
+
+        let server_port = self.select_server_port()?;
+        project.server_address = Some(self.server_address(server_port));
+        let mut run_backend_server = self.launch_backend_server(server_port).await?;
+        let endpoint_test_results = self.test_endpoints_on_server(
+            &mut run_backend_server, 
+            server_port, 
+            &endpoint_test_steps, 
         ).await;
 
-        println!("{}", rest_api_endpoints);
+        let passed_count = endpoint_test_results.iter().filter(|result| result.passed).count();
+        report.add_artifact(format!("{} of {} endpoint tests passed", passed_count, endpoint_test_results.len()));
+        for result in endpoint_test_results.iter().filter(|result| !result.passed) {
+            report.add_failed_check(result.failure_description());
+        }
+        self.attributes.set_status(AgentStatus::Finished);
+        Ok(report)
+    }
+
+    // Extract the endpoint schema of the code on disk, and save it with its OpenAPI document.
+    pub async fn write_api_endpoint_schema(
+        &mut self, 
+        project: &mut Project
+    ) -> Result<AgentReport, Box<dyn std::error::Error>> {
+        let mut report = AgentReport::new(&self.attributes.position);
+        project.backend_code = Some(BackendCodePersistence::read_executable_main_contents());
+        self.attributes.set_status(AgentStatus::Working);
+
+        let api_endpoints = self.extract_rest_api_endpoints().await?;
+        for route_conflict in EndpointRoute::find_route_conflicts(&api_endpoints) {
+            report.add_failed_check(route_conflict);
+        }
+        let api_endpoints_string = serde_json::to_string_pretty(&api_endpoints)
+            .expect("Failed to encode API endpoints.");
+        BackendCodePersistence::save_api_endpoints(&api_endpoints_string);
+        report.add_artifact(format!("{} API endpoints", api_endpoints.len()));
+        project.api_endpoint_schema = Some(api_endpoints);
+        self.export_open_api_document(project);
+
+        self.attributes.set_status(AgentStatus::Finished);
+        Ok(report)
+    }
+}
 
-        rest_api_endpoints
+impl Default for BackendDeveloperAgent {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -127,181 +552,221 @@ impl Agent for BackendDeveloperAgent {
     async fn execute(
         &mut self, 
         project: &mut Project
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<AgentReport, Box<dyn std::error::Error>> {
+        let mut report = AgentReport::new(&self.attributes.position);
         while self.attributes.status != AgentStatus::Finished {
             match &self.attributes.status {
 
                 AgentStatus::Discovery => {
-                    self.generate_initial_backend_code(project).await;
-                    self.attributes.status = AgentStatus::Working;
+
+                    // Address defects filed by QA on existing code, instead of starting over.
+                    if project.backend_code.is_some() && !project.defects.is_empty() {
+                        let defect_summaries: Vec<String> = project.defects
+                            .drain(..)
+                            .map(|defect| defect.summary())
+                            .collect();
+                        self.record_bug(format!(
+                            "QA found these defects in black-box testing:\n{}", 
+                            defect_summaries.join("\n"), 
+                        ))?;
+                    } else {
+                        self.generate_initial_backend_code(project).await;
+                    }
+                    self.attributes.set_status(AgentStatus::Working);
                     continue;
                 },
 
                 AgentStatus::Working => {
-                    if self.bug_count == 0 {
+                    if self.bug_errors.is_none() {
                         self.improve_backend_code(project).await;
                     } else {
                         self.fix_code_bugs(project).await;
                     }
-                    self.attributes.status = AgentStatus::UnitTesting;
+                    self.is_code_reviewed = false;
+                    self.attributes.set_status(AgentStatus::UnitTesting);
                     continue;
                 },
 
                 AgentStatus::UnitTesting => {
 
-                    // Guard: ensure AI safety.
-                    CommandLine::print_agent_unit_test_message(
-                        &self.attributes.position, 
-                        "Backend Code Unit Testing: Requesting user input", 
-                    );
-
-                    let is_safe_code = CommandLine::confirm_safe_code();
-                    if !is_safe_code {
-                        panic!("Better go work on some AI alignment instead…");
+                    // Have the code reviewed, and address blocking issues before a human is asked to run it.
+                    if !self.is_code_reviewed && self.review_rounds < self.maximum_review_rounds {
+                        self.review_rounds += 1;
+                        CodeReviewAgent::new().execute(project).await?;
+                        self.is_code_reviewed = true;
+                        let blocking_issues: Vec<String> = project.review_issues
+                            .iter()
+                            .filter(|review_issue| review_issue.is_blocking())
+                            .map(|review_issue| review_issue.summary())
+                            .collect();
+                        // Review rounds have their own budget, so they don't count as bugs.
+                        if !blocking_issues.is_empty() {
+                            self.bug_errors = Some(format!(
+                                "Code review found these blocking issues:\n{}", 
+                                blocking_issues.join("\n"), 
+                            ));
+                            self.attributes.set_status(AgentStatus::Working);
+                            continue;
+                        }
                     }
 
-                    // Build and test code.
-                    CommandLine::print_agent_unit_test_message(
-                        &self.attributes.position, 
-                        "Backend Code Unit Testing: building project…", 
-                    );
+                    // Guard: ensure AI safety.
+                    self.confirm_safe_code()?;
 
                     // Build code.
-                    let build_backend_server = Command::new("cargo")
-                        .arg("build")
-                        .current_dir(BackendCodePersistence::get_backend_code_project_path())
-                        .stdout(Stdio::piped())
-                        .stderr(Stdio::piped())
-                        .output()
-                        .expect("Failed to build backend application.");
-
-                    // Determine if there are any build errors.
-                    if build_backend_server.status.success() {
-                        self.bug_count = 0;
-                        CommandLine::print_agent_unit_test_message(
-                            &self.attributes.position, 
-                            "Backend Code Unit Testing: Test server build successful!", 
-                        );
-                    } else {
-                        let error_output = build_backend_server.stderr;
-                        let error_string = String::from_utf8(error_output)
-                            .unwrap_or("".to_string());
+                    if let Err(error_string) = self.build_backend_server() {
 
                         // Update error statistics.
-                        self.bug_count += 1;
-                        self.bug_errors = Some(error_string);
-
-                        // Exit if too many bugs.
-                        if self.bug_count > 10 {
-                            CommandLine::print_agent_error_message(
-                                &self.attributes.position, 
-                                "Backend Code Unit Testing: Too many bugs found in code.", 
-                            );
-                            panic!("Error: Too many bugs");
-                        }
+                        self.record_bug(error_string)?;
 
                         // Pass back for rework.
-                        self.attributes.status = AgentStatus::Working;
+                        self.attributes.set_status(AgentStatus::Working);
                         continue;
                     }
 
                     // Extract API endpoints.
-                    let api_endpoints_string = self.extract_rest_api_endpoints().await;
-                    
-                    // Convert API endpointsinto values.
-                    let api_endpoints: Vec<EndpointRoute> = serde_json::from_str(&api_endpoints_string.as_str())
-                        .expect("Failed to decode API endpoints.");
-
-                    // Define endpoints to check.
-                    let api_endpoints_to_check: Vec<EndpointRoute> = api_endpoints
-                        .iter()
-                        .filter(|&endpoint_route| {
-                            endpoint_route.method == HTTPMethod::Get && endpoint_route.is_route_dynamic == false
-                        })
-                        .cloned()
-                        .collect();
+                    let api_endpoints = self.extract_rest_api_endpoints().await?;
+                    let api_endpoints_string = serde_json::to_string_pretty(&api_endpoints)
+                        .expect("Failed to encode API endpoints.");
+
+                    // Pass conflicting route registrations back for rework.
+                    let route_conflicts = EndpointRoute::find_route_conflicts(&api_endpoints);
+                    if !route_conflicts.is_empty() {
+                        let error_message = format!("Backend Code Unit Testing: {}", route_conflicts.join(" "));
+                        EventBus::publish_issue_message(
+                            &self.attributes.position, 
+                            error_message.as_str(), 
+                        );
+                        self.record_bug(route_conflicts.join("\n"))?;
+                        self.attributes.set_status(AgentStatus::Working);
+                        continue;
+                    }
+
+                    // Plan tests for every endpoint, chaining CRUD operations per resource.
+                    let endpoint_test_steps = plan_endpoint_tests(&api_endpoints);
 
                     // Store API endpoints.
-                    project.api_endpoint_schema = Some(api_endpoints_to_check.clone());
+                    project.api_endpoint_schema = Some(api_endpoints);
 
-                    // Run backend application.
-                    CommandLine::print_agent_unit_test_message(
-                        &self.attributes.position, 
-                        "Backend Code Unit Testing: Starting web server…", 
-                    );
+                    // Pick the port the server will bind, and that tests will probe.
+                    let server_port = match self.select_server_port() {
+                        Ok(port) => port,
+                        Err(error_message) => {
+                            EventBus::publish_issue_message(
+                                &self.attributes.position, 
+                                error_message.as_str(), 
+                            );
+                            return Err(error_message.into());
+                        },
+                    };
+                    project.server_address = Some(self.server_address(server_port));
+
+                    // Run backend application, and pass it back for rework when it does not start.
+                    let mut run_backend_server = match self.launch_backend_server(server_port).await {
+                        Ok(run_backend_server) => run_backend_server,
+                        Err(error_string) => {
+                            self.record_bug(error_string)?;
+                            self.attributes.set_status(AgentStatus::Working);
+                            continue;
+                        },
+                    };
+
+                    // Run endpoint tests.
+                    let endpoint_test_results = self.test_endpoints_on_server(
+                        &mut run_backend_server, 
+                        server_port, 
+                        &endpoint_test_steps, 
+                    ).await;
 
-                    // Execute running server.
-                    let mut run_backend_server = Command::new("cargo")
-                        .arg("run")
-                        .current_dir(BackendCodePersistence::get_backend_code_project_path())
-                        .stdout(Stdio::piped())
-                        .stderr(Stdio::piped())
-                        .spawn()
-                        .expect("Failed to run backend application");
-
-                    // Let user know testing on server will take place soon.
-                    CommandLine::print_agent_unit_test_message(
-                        &self.attributes.position, 
-                        "Backend Code Unit Testing Launching tests on server in 5 seconds…", 
-                    );
+                    BackendCodePersistence::save_api_endpoints(&api_endpoints_string);
+                    self.export_open_api_document(project);
 
-                    // Wait for 5 seconds.
-                    let delay = Duration::from_secs(5);
-                    time::sleep(delay).await;
+                    // Pass failing endpoints back for rework.
+                    let endpoint_failures: Vec<String> = endpoint_test_results
+                        .iter()
+                        .filter(|result| !result.passed)
+                        .map(|result| result.failure_description())
+                        .collect();
+                    if !endpoint_failures.is_empty() {
+                        self.record_bug(format!(
+                            "The code builds, but these endpoints misbehave at runtime:\n{}", 
+                            endpoint_failures.join("\n"), 
+                        ))?;
+                        self.attributes.set_status(AgentStatus::Working);
+                        continue;
+                    }
 
-                    // Check status code.
-                    for endpoint in api_endpoints_to_check {
+                    // Pass failing integration tests back for rework.
+                    if let Err(failure_report) = self.run_integration_tests(project) {
+                        self.record_bug(format!(
+                            "The generated integration tests in `tests/api_endpoints.rs` fail:\n{}", 
+                            failure_report, 
+                        ))?;
+                        self.attributes.set_status(AgentStatus::Working);
+                        continue;
+                    }
 
-                        // Confirm URL testing.
-                        let testing_message = format!("Testing endpoint '{}'…", endpoint.route);
-                        CommandLine::print_agent_unit_test_message(
+                    // Check off the task this iteration worked on, and move on to the next one with a fresh review budget.
+                    self.bug_errors = None;
+                    self.review_rounds = 0;
+                    if let Some(backend_task) = project.backend_tasks
+                        .iter_mut()
+                        .find(|backend_task| backend_task.status == BackendTaskStatus::InProgress)
+                    {
+                        backend_task.status = BackendTaskStatus::Done;
+                        let finished_backend_task_title = backend_task.title.clone();
+                        let (done_count, task_count) = backend_task_progress(&project.backend_tasks);
+                        let progress_message = format!(
+                            "Backend Tasks: {} of {} done, finished \"{}\".", 
+                            done_count, task_count, finished_backend_task_title, 
+                        );
+                        EventBus::publish_unit_test_message(
                             &self.attributes.position, 
-                            testing_message.as_str(), 
+                            progress_message.as_str(), 
                         );
+                    }
+                    if project.backend_tasks
+                        .iter()
+                        .any(|backend_task| backend_task.status != BackendTaskStatus::Done)
+                    {
+                        self.attributes.set_status(AgentStatus::Working);
+                        continue;
+                    }
+
+                    report.add_artifact("Backend code".to_string());
+                    report.add_artifact(format!(
+                        "{} API endpoints", 
+                        project.api_endpoint_schema.as_ref().map_or(0, |endpoints| endpoints.len()), 
+                    ));
+                    report.add_artifact("Integration tests".to_string());
+                    self.generate_rust_client(project, &mut report);
+                    BackendCodePersistence::save_typescript_client(
+                        &typescript_client_module(project.api_endpoint_schema.as_deref().unwrap_or_default()), 
+                    );
+                    report.add_artifact("TypeScript client".to_string());
+                    if self.bug_count > 0 {
+                        report.add_warning(format!("{} bugs were fixed before the tests passed.", self.bug_count));
+                    }
 
-                        // Create client with timeout.
-                        let client = Client::builder()
-                            .timeout(Duration::from_secs(5))
-                            .build()
-                            .expect("Unable to instantiate reqwest client.");
-
-                        // Test URL.
-                        let url = format!("http://localhost:8080{}", endpoint.route);
-                        match check_status_code(&client, &url).await {
-                            Ok(status_code) => {
-                                if status_code != 200 {
-                                    let error_message = format!("WARNING: Failed to call backend URL endpoint {}", endpoint.route);
-                                    CommandLine::print_agent_error_message(
-                                        &self.attributes.position, 
-                                        error_message.as_str(), 
-                                    );
-                                }
-                            },
-                            Err(error) => {
-                                // kill $(lsof -t -i:8080)
-                                run_backend_server
-                                    .kill()
-                                    .expect("Failed to kill backend webserver.");
-                                let error_message = format!("Error checking backend {}", error);
-                                CommandLine::print_agent_error_message(
-                                    &self.attributes.position, 
-                                    error_message.as_str(), 
-                                );
-                            }
+                    // Review the final code when it changed after the last review round, and fail on what still blocks.
+                    if !self.is_code_reviewed && self.maximum_review_rounds > 0 {
+                        CodeReviewAgent::new().execute(project).await?;
+                        self.is_code_reviewed = true;
+                    }
+                    for review_issue in &project.review_issues {
+                        if review_issue.is_blocking() {
+                            report.add_failed_check(review_issue.summary());
+                        } else {
+                            report.add_warning(review_issue.summary());
                         }
                     }
 
-                    BackendCodePersistence::save_api_endpoints(&api_endpoints_string);
-                    CommandLine::print_agent_unit_test_message(
+                    EventBus::publish_unit_test_message(
                         &self.attributes.position, 
                         "Backend testing complete!", 
                     );
 
-                    run_backend_server
-                        .kill()
-                        .expect("Failed to kill backend webserver on completion.");
-
-                    self.attributes.status = AgentStatus::Finished;
+                    self.attributes.set_status(AgentStatus::Finished);
                 },
 
                 AgentStatus::Finished => {},
@@ -309,7 +774,47 @@ impl Agent for BackendDeveloperAgent {
             }
         }
 
-        Ok(())
+        Ok(report)
+    }
+
+    fn attributes(&self) -> &AgentAttributes {
+        &self.attributes
+    }
+
+    fn can_run(&self, project: &Project) -> bool {
+        !project.description.is_empty()
+    }
+
+    fn reset(&mut self) {
+        self.attributes.set_status(AgentStatus::Discovery);
+        self.bug_errors = None;
+        self.bug_count = 0;
+        self.review_rounds = 0;
+        self.is_code_reviewed = false;
+        self.server_port = None;
+    }
+
+    fn requires(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::Description,
+            ProjectField::BackendTasks,
+            ProjectField::Defects,
+        ]
+    }
+
+    fn produces(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::BackendCode,
+            ProjectField::BackendTasks,
+            ProjectField::ApiEndpointSchema,
+            ProjectField::ServerAddress,
+            ProjectField::ReviewIssues,
+            ProjectField::Defects,
+        ]
+    }
+
+    fn prompts_user(&self) -> bool {
+        true
     }
 }
 
@@ -318,6 +823,15 @@ impl Agent for BackendDeveloperAgent {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_record_bug_fails_past_maximum_bug_count() {
+        let mut agent = BackendDeveloperAgent::new();
+        agent.maximum_bug_count = 1;
+        assert_eq!(agent.record_bug("error[E0425]: cannot find value".to_string()), Ok(()));
+        assert_eq!(agent.bug_errors.as_deref(), Some("error[E0425]: cannot find value"));
+        assert!(agent.record_bug("error[E0425]: cannot find value".to_string()).is_err());
+    }
+
     // Disable this test for global `cargo test` commands.
     //#[tokio::test]
     #[allow(dead_code)]
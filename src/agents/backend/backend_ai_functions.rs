@@ -13,19 +13,27 @@
  *  of the REST API endpoints schema to be returned as a 
  *  proper boolean value.
  *
+ *  It was also modified to require the generated server to
+ *  keep reading its bind address from the BACKEND_HOST and
+ *  BACKEND_PORT environment variables.
+ *
+ *  It was also modified to take the planned BACKEND_TASKS
+ *  into account, and to focus improvements on a CURRENT_TASK.
+ *
  */
 
 use ai_functions::ai_function;
 
 #[ai_function]
 pub fn print_backend_webserver_code(_project_description_and_template: &str) {
-    /// INPUT: Takes in a PROJECT_DESCRIPTION and CODE_TEMPLATE for a website backend build
+    /// INPUT: Takes in a PROJECT_DESCRIPTION, CODE_TEMPLATE and the planned BACKEND_TASKS for a website backend build
     /// IMPORTANT: The backend code is ONLY an example. If the Project Description requires it, make as many changes as you like.
     /// IMPORTANT: You do not need to follow the backend code exactly. Write functions that make sense for the users request if required.
     /// FUNCTION: Takes an existing set of code marked as CODE_TEMPLATE and updates or re-writes it to work for the purpose in the PROJECT_DESCRIPTION
     /// IMPORTANT: The following libraries are already installed
     ///   reqwest, serde, serde_json, tokio, actix-web, async-trait, actix_cors
     /// No other external libraries should be used. Write functions that fit with the description from the PROJECT_DESCRIPTION
+    /// IMPORTANT: The server MUST bind to the host and port read from the BACKEND_HOST and BACKEND_PORT environment variables, exactly like the CODE_TEMPLATE does.
     /// OUTPUT: Print ONLY the code, nothing else. This function ONLY prints code.
     /// Do NOT print the code surrounded by triple backticks to designate a code section. ONLY print the code itself.
     println!(OUTPUT)
@@ -33,13 +41,15 @@ pub fn print_backend_webserver_code(_project_description_and_template: &str) {
 
 #[ai_function]
 pub fn print_improved_webserver_code(_project_description_and_template: &str) {
-    /// INPUT: Takes in a PROJECT_DESCRIPTION and CODE_TEMPLATE for a website backend build
+    /// INPUT: Takes in a PROJECT_DESCRIPTION, CODE_TEMPLATE and CURRENT_TASK for a website backend build
     /// FUNCTION: Performs the following tasks:
     ///   1. Removes any bugs in the code and adds minor additional functionality
     ///   2. Makes sure everything requested in the spec from a backend standpoint was followed. If not, add the feature. No code should be implemented later. Everything should be written now.
-    ///   3. ONLY writes the code. No commentary. No surrounding triple backticks to designatte a code section. ONLY the code.
+    ///   3. If a CURRENT_TASK is given, makes sure it is fully implemented, without breaking what already works
+    ///   4. ONLY writes the code. No commentary. No surrounding triple backticks to designatte a code section. ONLY the code.
     /// IMPORTANT: The following libraries are already installed. Does not use ANY libraries other than what was provided in the template
     ///   reqwest, serde, serde_json, tokio, actix-web, async-trait
+    /// IMPORTANT: Keeps binding the server to the host and port read from the BACKEND_HOST and BACKEND_PORT environment variables.
     println!(OUTPUT)
 }
 
@@ -48,6 +58,7 @@ pub fn print_fixed_code(_broken_code_with_bugs: &str) {
     /// INPUT: Takes in Rust BROKEN_CODE and the ERROR_BUGS found
     /// FUNCTION: Removes bugs from code
     /// IMPORTANT: Only prints out the new and improved code. No commentary or anything else. No surrounding triple backticks to designatte a code section. ONLY the code.
+    /// IMPORTANT: Keeps binding the server to the host and port read from the BACKEND_HOST and BACKEND_PORT environment variables.
     println!(OUTPUT)
 }
 
@@ -8,4 +8,5 @@
  */
 
 pub mod attributes;
+pub mod report;
 pub mod traits;
\ No newline at end of file
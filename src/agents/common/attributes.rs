@@ -7,7 +7,12 @@
  *
  */
 
-#[derive(Debug, PartialEq)]
+use crate::utilities::event_bus::{ AgentEvent, EventBus };
+
+use serde::{ Deserialize, Serialize };
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
  pub enum AgentStatus {
     Discovery,
     Working,
@@ -21,3 +26,16 @@ pub struct AgentAttributes {
     pub position: String,
     pub status: AgentStatus,
 }
+
+impl AgentAttributes {
+    // Move to a new status, letting event subscribers follow the agent's progress.
+    pub fn set_status(&mut self, status: AgentStatus) {
+        if self.status != status {
+            EventBus::publish(AgentEvent::StatusChanged {
+                position: self.position.clone(),
+                status: status.clone(),
+            });
+        }
+        self.status = status;
+    }
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+/*
+ *  agents/common/report.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use serde::{ Deserialize, Serialize };
+
+// What one run of an agent left behind, for the manager and run reports.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct AgentReport {
+    pub position: String,
+    pub artifacts: Vec<String>,
+    pub llm_calls: u32,
+    pub warnings: Vec<String>,
+    // Checks that still fail, such as endpoint tests or unresolved defects, which make the run unsuccessful.
+    #[serde(default)]
+    pub failed_checks: Vec<String>,
+}
+
+impl AgentReport {
+    pub fn new(position: &str) -> Self {
+        Self {
+            position: position.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn add_artifact(&mut self, artifact: String) {
+        self.artifacts.push(artifact);
+    }
+
+    pub fn add_warning(&mut self, warning: String) {
+        self.warnings.push(warning);
+    }
+
+    pub fn add_failed_check(&mut self, failed_check: String) {
+        self.failed_checks.push(failed_check);
+    }
+}
@@ -7,15 +7,39 @@
  *
  */
 
+use super::attributes::AgentAttributes;
+use super::report::AgentReport;
+
 use crate::model::project::Project;
+use crate::model::project_field::ProjectField;
 
 use async_trait::async_trait;
 use std::fmt::Debug;
 
 #[async_trait]
-pub trait Agent: Debug {
+pub trait Agent: Debug + Send {
+
+    // This function will allow agents to execute their logic, and report what they produced.
+    async fn execute(&mut self, project: &mut Project) -> Result<AgentReport, Box<dyn std::error::Error>>;
+
+    // The agent's objective, position and status.
+    fn attributes(&self) -> &AgentAttributes;
+
+    // Whether the project has what the agent needs to do its work.
+    fn can_run(&self, project: &Project) -> bool;
+
+    // Put the agent back in its initial state, so it can run again from scratch.
+    fn reset(&mut self);
+
+    // The project fields this agent reads, so it runs after the agents that produce them.
+    fn requires(&self) -> Vec<ProjectField>;
+
+    // The project fields this agent writes, so the agents reading them run again when they change.
+    fn produces(&self) -> Vec<ProjectField>;
 
-    // This function will allow agents to execute their logic.
-    async fn execute(&mut self, project: &mut Project) -> Result<(), Box<dyn std::error::Error>>;
+    // Whether the agent may ask the user questions on the command line, so it never runs alongside another such agent.
+    fn prompts_user(&self) -> bool {
+        false
+    }
 
 }
\ No newline at end of file
@@ -0,0 +1,11 @@
+/*
+ *  agents/frontend.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+pub mod frontend_agent;
+pub mod frontend_ai_functions;
@@ -0,0 +1,253 @@
+/*
+ *  agents/frontend/frontend_agent.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::frontend_ai_functions::{ print_fixed_frontend_code, print_frontend_code };
+use super::super::common::attributes::{ AgentAttributes, AgentStatus };
+use super::super::common::report::AgentReport;
+use super::super::common::traits::Agent;
+
+use crate::model::project::Project;
+use crate::model::project_field::ProjectField;
+use crate::model::server_address::ServerAddress;
+use crate::utilities::ai_tasks::ai_task_request_decoded;
+use crate::utilities::backend_server::BackendServer;
+use crate::utilities::event_bus::EventBus;
+use crate::utilities::frontend_code_persistence::FrontendCodePersistence;
+use crate::utilities::frontend_smoke_check::{
+    is_valid_frontend_file_name, smoke_check_frontend, smoke_check_running_frontend, 
+    FRONTEND_CONFIGURATION_FILE, 
+};
+use crate::utilities::networking::{ find_available_port, DEFAULT_BACKEND_PORT };
+
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+const DEFAULT_MAXIMUM_FIX_ATTEMPTS: u8 = 3;
+const DEFAULT_SERVER_STARTUP_DEADLINE: Duration = Duration::from_secs(30);
+
+// Frontend Developer
+#[derive(Debug)]
+pub struct FrontendDeveloperAgent {
+    pub attributes: AgentAttributes,
+    // Base URL the frontend calls instead of the backend's own address, such as a deployed API.
+    pub api_base_url: Option<String>,
+    pub server_startup_deadline: Duration,
+    pub problems: Vec<String>,
+    pub fix_attempts: u8,
+    pub maximum_fix_attempts: u8,
+}
+
+impl FrontendDeveloperAgent {
+    pub fn new() -> Self {
+        let attributes = AgentAttributes {
+            objective: "Develops a static HTML, CSS and JavaScript frontend for the backend API.".to_string(),
+            position: "Frontend Developer".to_string(),
+            status: AgentStatus::Discovery,
+        };
+
+        Self {
+            attributes,
+            api_base_url: None,
+            server_startup_deadline: DEFAULT_SERVER_STARTUP_DEADLINE,
+            problems: vec![],
+            fix_attempts: 0,
+            maximum_fix_attempts: DEFAULT_MAXIMUM_FIX_ATTEMPTS,
+        }
+    }
+
+    // Use the configured base URL, or else the address the backend listens on once the run ends.
+    // The port the backend developer tested on was only free for its test server, so the default port is used instead.
+    fn api_base_url(&self, project: &Project) -> Option<String> {
+        self.api_base_url
+            .clone()
+            .or_else(|| project.server_address.as_ref().map(|server_address| ServerAddress {
+                host: server_address.host.clone(),
+                port: DEFAULT_BACKEND_PORT,
+            }.base_url()))
+    }
+
+    // Add the configuration script, which is written here rather than by the model so the base URL is right.
+    fn save_frontend_code(&self, project: &mut Project, mut files: BTreeMap<String, String>) {
+        files.insert(
+            FRONTEND_CONFIGURATION_FILE.to_string(), 
+            format!("window.API_BASE_URL = {:?};\n", self.api_base_url(project).unwrap_or_default()), 
+        );
+
+        // Only write files that stay inside the frontend directory; the smoke check reports the others.
+        let saved_files: BTreeMap<String, String> = files
+            .iter()
+            .filter(|(file_name, _)| is_valid_frontend_file_name(file_name))
+            .map(|(file_name, contents)| (file_name.clone(), contents.clone()))
+            .collect();
+        FrontendCodePersistence::save_frontend_code(&saved_files);
+        project.frontend_code = Some(files);
+    }
+
+    async fn generate_frontend_code(&mut self, project: &mut Project) -> Result<(), String> {
+        let message_context = format!(
+            "PROJECT_DESCRIPTION: {} \n API_ENDPOINTS: {} \n", 
+            project.description, 
+            serde_json::to_string(&project.api_endpoint_schema).expect("Failed to encode API endpoints."), 
+        );
+
+        let files: BTreeMap<String, String> = ai_task_request_decoded(
+            message_context, 
+            &self.attributes.position, 
+            get_function_string!(print_frontend_code), 
+            print_frontend_code, 
+        ).await?;
+
+        self.save_frontend_code(project, files);
+        Ok(())
+    }
+
+    // Run the built backend on a free port of its selected host, and check the saved frontend against it in a browser's stead.
+    async fn smoke_check_running_frontend(&self, project: &Project) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let server_host = project.server_address
+            .as_ref()
+            .map(|server_address| server_address.host.clone())
+            .ok_or("The backend developer did not select an address for the server.")?;
+        let server_address = ServerAddress {
+            port: find_available_port(&server_host)?,
+            host: server_host,
+        };
+        let mut backend_server = BackendServer::launch_backend_binary("frontend", &server_address.host, server_address.port)?;
+        if let Err(error) = backend_server
+            .wait_until_ready(&server_address.host, server_address.port, self.server_startup_deadline)
+            .await
+        {
+            backend_server.stop();
+            return Err(format!("The backend server failed to start for the frontend smoke check. {}", error).into());
+        }
+
+        let problems = smoke_check_running_frontend(
+            &FrontendCodePersistence::get_frontend_code_project_path(), 
+            project.api_endpoint_schema.as_deref().unwrap_or_default(), 
+            &server_address.base_url(), 
+        ).await;
+        backend_server.stop();
+        Ok(problems)
+    }
+
+    async fn fix_frontend_code(&mut self, project: &mut Project) -> Result<(), String> {
+        let message_context = format!(
+            "FRONTEND_CODE: {} \n PROBLEMS: {:?} \n", 
+            serde_json::to_string(&project.frontend_code).expect("Failed to encode frontend code."), 
+            self.problems, 
+        );
+
+        let files: BTreeMap<String, String> = ai_task_request_decoded(
+            message_context, 
+            &self.attributes.position, 
+            get_function_string!(print_fixed_frontend_code), 
+            print_fixed_frontend_code, 
+        ).await?;
+
+        self.save_frontend_code(project, files);
+        Ok(())
+    }
+}
+
+impl Default for FrontendDeveloperAgent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Agent for FrontendDeveloperAgent {
+    async fn execute(&mut self, project: &mut Project) -> Result<AgentReport, Box<dyn std::error::Error>> {
+        let mut report = AgentReport::new(&self.attributes.position);
+        while self.attributes.status != AgentStatus::Finished {
+            match self.attributes.status {
+
+                AgentStatus::Discovery => {
+                    if !self.can_run(project) {
+                        EventBus::publish_issue_message(
+                            &self.attributes.position, 
+                            "Frontend Development: There is no API to build a frontend for yet.", 
+                        );
+                        self.attributes.set_status(AgentStatus::Finished);
+                        continue;
+                    }
+                    self.generate_frontend_code(project).await?;
+                    self.attributes.set_status(AgentStatus::UnitTesting);
+                },
+
+                AgentStatus::Working => {
+                    self.fix_attempts += 1;
+                    self.fix_frontend_code(project).await?;
+                    self.attributes.set_status(AgentStatus::UnitTesting);
+                },
+
+                AgentStatus::UnitTesting => {
+                    let files = project.frontend_code.clone().unwrap_or_default();
+                    let endpoints = project.api_endpoint_schema.as_deref().unwrap_or_default();
+                    self.problems = smoke_check_frontend(&files, endpoints);
+                    if self.problems.is_empty() {
+                        self.problems = self.smoke_check_running_frontend(project).await?;
+                    }
+                    if self.problems.is_empty() {
+                        EventBus::publish_unit_test_message(
+                            &self.attributes.position, 
+                            "Frontend Smoke Check: the entry page loads its assets and calls the running API.", 
+                        );
+                        self.attributes.set_status(AgentStatus::Finished);
+                        continue;
+                    }
+
+                    let error_message = format!("Frontend Smoke Check: {}", self.problems.join(" "));
+                    EventBus::publish_issue_message(&self.attributes.position, error_message.as_str());
+                    if self.fix_attempts >= self.maximum_fix_attempts {
+                        return Err("Frontend smoke check still fails after the maximum number of fixes.".into());
+                    }
+                    self.attributes.set_status(AgentStatus::Working);
+                },
+
+                AgentStatus::Finished => {},
+
+            }
+        }
+
+        for file_name in project.frontend_code.iter().flat_map(|files| files.keys()) {
+            report.add_artifact(format!("frontend/{}", file_name));
+        }
+
+        Ok(report)
+    }
+
+    fn attributes(&self) -> &AgentAttributes {
+        &self.attributes
+    }
+
+    fn can_run(&self, project: &Project) -> bool {
+        project.api_endpoint_schema.is_some() && project.server_address.is_some()
+    }
+
+    fn reset(&mut self) {
+        self.attributes.set_status(AgentStatus::Discovery);
+        self.problems.clear();
+        self.fix_attempts = 0;
+    }
+
+    fn requires(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::Description,
+            ProjectField::ApiEndpointSchema,
+            ProjectField::ServerAddress,
+        ]
+    }
+
+    fn produces(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::FrontendCode,
+        ]
+    }
+}
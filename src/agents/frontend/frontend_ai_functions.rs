@@ -0,0 +1,37 @@
+/*
+ *  agents/frontend/frontend_ai_functions.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use ai_functions::ai_function;
+
+#[ai_function]
+pub fn print_frontend_code(_project_description_and_api_endpoints: &str) {
+    /// INPUT: Takes in a PROJECT_DESCRIPTION and the API_ENDPOINTS schema of the website backend
+    /// FUNCTION: Writes a static website frontend in plain HTML, CSS and JavaScript which lets users do everything in the PROJECT_DESCRIPTION through the API_ENDPOINTS
+    /// IMPORTANT: There is NO build step. Do not use npm packages, frameworks, TypeScript or JavaScript modules from the web.
+    /// IMPORTANT: Every HTML page MUST load `config.js` before its other scripts. `config.js` already exists and sets `window.API_BASE_URL`. Do not write it.
+    /// IMPORTANT: Every API call uses `fetch` with a URL starting with `window.API_BASE_URL`, followed by a route from API_ENDPOINTS.
+    /// IMPORTANT: The entry page is `index.html`. File names are plain names such as `styles.css`, without directories.
+    /// OUTPUT: Prints ONLY a JSON object mapping each file name to its contents, nothing else:
+    ///   {
+    ///     "index.html": "<!DOCTYPE html>...",
+    ///     "styles.css": "body { ... }",
+    ///     "app.js": "async function loadTasks() { const response = await fetch(`${window.API_BASE_URL}/task`); ... }"
+    ///   }
+    /// Do NOT surround the JSON with triple backticks.
+    println!(OUTPUT)
+}
+
+#[ai_function]
+pub fn print_fixed_frontend_code(_frontend_code_and_problems: &str) {
+    /// INPUT: Takes in the FRONTEND_CODE of a static website as a JSON object mapping file names to contents, and the PROBLEMS found when checking it
+    /// FUNCTION: Fixes every problem, keeping plain HTML, CSS and JavaScript without a build step
+    /// IMPORTANT: Every HTML page MUST load `config.js`, which sets `window.API_BASE_URL`, and call the API with `fetch`.
+    /// OUTPUT: Prints ONLY the complete fixed JSON object mapping each file name to its contents, nothing else. Do NOT surround the JSON with triple backticks.
+    println!(OUTPUT)
+}
@@ -7,5 +7,7 @@
  *
  */
 
+pub mod agent_graph;
+pub mod agent_registry;
 pub mod managing_agent;
 pub mod managing_ai_functions;
\ No newline at end of file
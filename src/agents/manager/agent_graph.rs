@@ -0,0 +1,648 @@
+/*
+ *  agents/manager/agent_graph.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::super::common::report::AgentReport;
+use super::super::common::traits::Agent;
+
+use crate::model::project::Project;
+use crate::model::project_field::ProjectField;
+use crate::model::run_state::RunState;
+use crate::utilities::ai_tasks::count_ai_task_requests;
+use crate::utilities::event_bus::{ AgentEvent, EventBus };
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use tokio::task::JoinSet;
+use tracing::Instrument;
+
+// Fields written to the project directories on disk, which only one agent at a time may touch.
+const ON_DISK_FIELDS: &[ProjectField] = &[ProjectField::BackendCode, ProjectField::FrontendCode];
+
+// An agent in the graph, which is reset when its inputs change.
+#[derive(Debug)]
+struct AgentNode {
+    agent: Option<Box<dyn Agent>>,
+    requires: Vec<ProjectField>,
+    produces: Vec<ProjectField>,
+    is_pending: bool,
+    is_completed: bool,
+    // Whether the agent writes code on disk or prompts the user, so it must not run alongside another such agent.
+    is_exclusive: bool,
+}
+
+// Agents ordered by the project fields they require and produce.
+// An agent depends on the agents added before it that produce a field it requires.
+// Agents whose dependencies are done run concurrently, each on its own copy of the project,
+// which is discarded when the agent fails so the project rolls back to before its run.
+// Agents writing code on disk or prompting the user run one at a time, since they share directories and stdin.
+// When an agent changes a field, the agents requiring it run again; agents added earlier only
+// run again for a limited number of feedback rounds, such as defects sent back by QA.
+// After each round, the project and the completed agents are saved to the checkpoint, if any,
+// so a run that stops can be resumed.
+#[derive(Debug)]
+pub struct AgentGraph {
+    nodes: Vec<AgentNode>,
+    maximum_feedback_rounds: u8,
+    pub checkpoint_path: Option<PathBuf>,
+    pub reports: Vec<AgentReport>,
+}
+
+impl AgentGraph {
+    pub fn new(maximum_feedback_rounds: u8) -> Self {
+        Self {
+            nodes: vec![],
+            maximum_feedback_rounds,
+            checkpoint_path: None,
+            reports: vec![],
+        }
+    }
+
+    pub fn add_agent(&mut self, agent: Box<dyn Agent>) {
+        let produces = agent.produces();
+        let is_exclusive = agent.prompts_user() || produces.iter().any(|field| ON_DISK_FIELDS.contains(field));
+        self.nodes.push(AgentNode {
+            requires: agent.requires(),
+            produces,
+            is_exclusive,
+            agent: Some(agent),
+            is_pending: true,
+            is_completed: false,
+        });
+    }
+
+    // Positions of the agents whose last run succeeded.
+    pub fn completed_agents(&self) -> Vec<String> {
+        self.nodes
+            .iter()
+            .filter(|node| node.is_completed)
+            .filter_map(|node| node.agent.as_ref())
+            .map(|agent| agent.attributes().position.clone())
+            .collect()
+    }
+
+    // Treat agents as done, such as those an interrupted run completed, so they only run again when their inputs change.
+    pub fn mark_completed(&mut self, positions: &[String]) {
+        for node in &mut self.nodes {
+            if node.agent.as_ref().is_some_and(|agent| positions.contains(&agent.attributes().position)) {
+                node.is_pending = false;
+                node.is_completed = true;
+            }
+        }
+    }
+
+    fn save_checkpoint(&self, project: &Project) {
+        let Some(checkpoint_path) = &self.checkpoint_path else {
+            return;
+        };
+        let run_state = RunState {
+            project: project.clone(),
+            completed_agents: self.completed_agents(),
+        };
+        if let Err(error) = run_state.save(checkpoint_path) {
+            tracing::warn!(%error, "unable to save run state");
+        }
+    }
+
+    // The agents added before this one that produce a field it requires.
+    fn dependencies(&self, index: usize) -> Vec<usize> {
+        let node = &self.nodes[index];
+        (0..index)
+            .filter(|&other_index| self.nodes[other_index]
+                .produces
+                .iter()
+                .any(|field| node.requires.contains(field)))
+            .collect()
+    }
+
+    // The pending agents whose dependencies have all run.
+    fn ready_agents(&self) -> Vec<usize> {
+        (0..self.nodes.len())
+            .filter(|&index| self.nodes[index].is_pending)
+            .filter(|&index| self.dependencies(index)
+                .iter()
+                .all(|&dependency| !self.nodes[dependency].is_pending))
+            .collect()
+    }
+
+    // Start an agent over so it runs again with the latest project.
+    fn schedule_rerun(&mut self, index: usize) {
+        let node = &mut self.nodes[index];
+        if !node.is_pending {
+            if let Some(agent) = node.agent.as_mut() {
+                agent.reset();
+            }
+            node.is_pending = true;
+            node.is_completed = false;
+        }
+    }
+
+    // Whether the latest report of every agent is free of failed checks, including agent failures.
+    pub fn is_successful(&self) -> bool {
+        let mut latest_reports: HashMap<&str, &AgentReport> = HashMap::new();
+        for report in &self.reports {
+            latest_reports.insert(&report.position, report);
+        }
+        latest_reports
+            .values()
+            .all(|report| report.failed_checks.is_empty())
+    }
+
+    pub async fn execute(&mut self, position: &str, project: &mut Project) {
+        let mut feedback_rounds = 0;
+        loop {
+            let ready_agents = self.ready_agents();
+            if ready_agents.is_empty() {
+                break;
+            }
+
+            let mut running_agents = JoinSet::new();
+            let mut running_agent_positions = HashMap::new();
+            let mut is_exclusive_agent_running = false;
+            for index in ready_agents {
+                let Some(mut agent) = self.nodes[index].agent.take() else {
+                    self.nodes[index].is_pending = false;
+                    continue;
+                };
+
+                // Skip agents whose inputs are missing, such as a frontend when the backend never got an API.
+                if !agent.can_run(project) {
+                    EventBus::publish(AgentEvent::AgentSkipped {
+                        position: agent.attributes().position.clone(),
+                    });
+                    let mut report = AgentReport::new(&agent.attributes().position);
+                    report.add_warning("Skipped, because the project does not have what this agent needs.".to_string());
+                    self.reports.push(report);
+                    self.nodes[index].agent = Some(agent);
+                    self.nodes[index].is_pending = false;
+                    continue;
+                }
+
+                // Leave other exclusive agents pending until the next round.
+                if self.nodes[index].is_exclusive {
+                    if is_exclusive_agent_running {
+                        self.nodes[index].agent = Some(agent);
+                        continue;
+                    }
+                    is_exclusive_agent_running = true;
+                }
+
+                EventBus::publish(AgentEvent::AgentStarted {
+                    position: agent.attributes().position.clone(),
+                });
+                let agent_span = tracing::info_span!("agent", position = %agent.attributes().position);
+                let agent_position = agent.attributes().position.clone();
+                let mut agent_project = project.clone();
+                let abort_handle = running_agents.spawn(async move {
+                    let (agent_result, llm_calls) = count_ai_task_requests(agent.execute(&mut agent_project))
+                        .await;
+                    let agent_result = agent_result
+                        .map(|report| AgentReport { llm_calls, ..report })
+                        .map_err(|error| error.to_string());
+                    (index, agent, agent_project, agent_result)
+                }.instrument(agent_span));
+                running_agent_positions.insert(abort_handle.id(), (index, agent_position));
+            }
+
+            let mut finished_agents = vec![];
+            let mut panicked_agents = vec![];
+            while let Some(join_result) = running_agents.join_next().await {
+                match join_result {
+                    Ok(finished_agent) => finished_agents.push(finished_agent),
+                    Err(join_error) => {
+                        if let Some((index, agent_position)) = running_agent_positions.remove(&join_error.id()) {
+                            panicked_agents.push((index, agent_position, join_error.to_string()));
+                        }
+                    },
+                }
+            }
+
+            // Merge in the order agents were added so concurrent runs always settle the same way.
+            finished_agents.sort_by_key(|(index, _, _, _)| *index);
+            panicked_agents.sort_by_key(|(index, _, _)| *index);
+
+            // A panicking agent takes its state down with it, so it fails like an agent error and never runs again.
+            for (index, agent_position, error) in panicked_agents {
+                EventBus::publish(AgentEvent::AgentFinished {
+                    position: agent_position.clone(),
+                    report: None,
+                    error: Some(error.clone()),
+                });
+                let error_message = format!("Agent Failure: {} {}", agent_position, error);
+                EventBus::publish_issue_message(position, error_message.as_str());
+                let mut report = AgentReport::new(&agent_position);
+                report.add_failed_check(format!("Failed, and its changes were rolled back: {}", error));
+                self.reports.push(report);
+                let node = &mut self.nodes[index];
+                node.is_pending = false;
+                node.is_completed = false;
+            }
+
+            let mut changed_fields: Vec<(usize, ProjectField)> = vec![];
+            for (index, mut agent, agent_project, agent_result) in finished_agents {
+                EventBus::publish(AgentEvent::AgentFinished {
+                    position: agent.attributes().position.clone(),
+                    report: agent_result.as_ref().ok().cloned(),
+                    error: agent_result.as_ref().err().cloned(),
+                });
+                let report = match agent_result {
+                    Ok(report) => report,
+                    Err(error) => {
+                        let error_message = format!("Agent Failure: {} {}", agent.attributes().position, error);
+                        EventBus::publish_issue_message(position, error_message.as_str());
+                        let mut report = AgentReport::new(&agent.attributes().position);
+                        report.add_failed_check(format!("Failed, and its changes were rolled back: {}", error));
+                        self.reports.push(report);
+                        agent.reset();
+                        let node = &mut self.nodes[index];
+                        node.agent = Some(agent);
+                        node.is_pending = false;
+                        node.is_completed = false;
+                        continue;
+                    },
+                };
+                self.reports.push(report);
+                for &field in &self.nodes[index].produces {
+                    if agent_project.field_value(field) != project.field_value(field) {
+                        project.copy_field(field, &agent_project);
+                        changed_fields.push((index, field));
+                    }
+                }
+                let node = &mut self.nodes[index];
+                node.agent = Some(agent);
+                node.is_pending = false;
+                node.is_completed = true;
+            }
+
+            let mut is_feedback_round = false;
+            for (producer, field) in changed_fields {
+                let consumers: Vec<usize> = (0..self.nodes.len())
+                    .filter(|&index| index != producer && self.nodes[index].requires.contains(&field))
+                    .collect();
+                for consumer in consumers {
+                    if consumer > producer {
+                        self.schedule_rerun(consumer);
+                    } else if feedback_rounds < self.maximum_feedback_rounds {
+                        self.schedule_rerun(consumer);
+                        is_feedback_round = true;
+                    }
+                }
+            }
+            if is_feedback_round {
+                feedback_rounds += 1;
+            }
+            self.save_checkpoint(project);
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::common::attributes::{ AgentAttributes, AgentStatus };
+    use crate::model::defect::Defect;
+    use crate::model::endpoint_route::HTTPMethod;
+
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    static EXECUTED_AGENTS: Mutex<Vec<&'static str>> = Mutex::new(vec![]);
+    static RUNNING_SLOW_AGENTS: Mutex<Vec<&'static str>> = Mutex::new(vec![]);
+    static OVERLAPPING_SLOW_AGENTS: Mutex<Vec<(&'static str, &'static str)>> = Mutex::new(vec![]);
+
+    #[derive(Debug)]
+    struct TestAgent {
+        attributes: AgentAttributes,
+        name: &'static str,
+        requires: Vec<ProjectField>,
+        produces: Vec<ProjectField>,
+    }
+
+    #[async_trait]
+    impl Agent for TestAgent {
+        async fn execute(&mut self, project: &mut Project) -> Result<AgentReport, Box<dyn std::error::Error>> {
+            EXECUTED_AGENTS.lock().unwrap().push(self.name);
+            self.attributes.set_status(AgentStatus::Finished);
+            match self.name {
+                "architect" => project.scope = None,
+                "backend" => {
+                    let version = if project.defects.is_empty() { "v1" } else { "v2" };
+                    project.backend_code = Some(version.to_string());
+                    project.defects.clear();
+                },
+                "frontend" => project.frontend_code = Some(Default::default()),
+                "qa" if project.backend_code.as_deref() == Some("v1") => {
+                    project.defects.push(Defect {
+                        scenario: "Create task".to_string(),
+                        step: Some(1),
+                        method: Some(HTTPMethod::Post),
+                        url: "/task".to_string(),
+                        request_body: None,
+                        expected_status_codes: vec![200],
+                        actual_status_code: Some(500),
+                        response_body: None,
+                        description: "server error".to_string(),
+                    });
+                },
+                _ => {},
+            }
+            let mut report = AgentReport::new(&self.attributes.position);
+            for defect in project.defects.iter().filter(|_| self.name == "qa") {
+                report.add_failed_check(defect.summary());
+            }
+            Ok(report)
+        }
+
+        fn attributes(&self) -> &AgentAttributes {
+            &self.attributes
+        }
+
+        fn can_run(&self, project: &Project) -> bool {
+            self.name != "reviewer" || !project.review_issues.is_empty()
+        }
+
+        fn reset(&mut self) {
+            self.attributes.set_status(AgentStatus::Discovery);
+        }
+
+        fn requires(&self) -> Vec<ProjectField> {
+            self.requires.clone()
+        }
+
+        fn produces(&self) -> Vec<ProjectField> {
+            self.produces.clone()
+        }
+    }
+
+    fn architect() -> Box<dyn Agent> {
+        Box::new(TestAgent {
+            attributes: AgentAttributes {
+                objective: String::new(),
+                position: "architect".to_string(),
+                status: AgentStatus::Discovery,
+            },
+            name: "architect",
+            requires: vec![ProjectField::Description],
+            produces: vec![ProjectField::Scope],
+        })
+    }
+
+    fn backend() -> Box<dyn Agent> {
+        Box::new(TestAgent {
+            attributes: AgentAttributes {
+                objective: String::new(),
+                position: "backend".to_string(),
+                status: AgentStatus::Discovery,
+            },
+            name: "backend",
+            requires: vec![ProjectField::Description, ProjectField::Defects],
+            produces: vec![ProjectField::BackendCode, ProjectField::Defects],
+        })
+    }
+
+    fn frontend() -> Box<dyn Agent> {
+        Box::new(TestAgent {
+            attributes: AgentAttributes {
+                objective: String::new(),
+                position: "frontend".to_string(),
+                status: AgentStatus::Discovery,
+            },
+            name: "frontend",
+            requires: vec![ProjectField::BackendCode],
+            produces: vec![ProjectField::FrontendCode],
+        })
+    }
+
+    fn reviewer() -> Box<dyn Agent> {
+        Box::new(TestAgent {
+            attributes: AgentAttributes {
+                objective: String::new(),
+                position: "reviewer".to_string(),
+                status: AgentStatus::Discovery,
+            },
+            name: "reviewer",
+            requires: vec![ProjectField::BackendCode],
+            produces: vec![ProjectField::ReviewIssues],
+        })
+    }
+
+    fn qa() -> Box<dyn Agent> {
+        Box::new(TestAgent {
+            attributes: AgentAttributes {
+                objective: String::new(),
+                position: "qa".to_string(),
+                status: AgentStatus::Discovery,
+            },
+            name: "qa",
+            requires: vec![ProjectField::BackendCode],
+            produces: vec![ProjectField::Defects],
+        })
+    }
+
+    // An agent that takes a while, recording which agents were running when it started.
+    #[derive(Debug)]
+    struct SlowAgent {
+        attributes: AgentAttributes,
+        name: &'static str,
+        produces: Vec<ProjectField>,
+        prompts_user: bool,
+    }
+
+    #[async_trait]
+    impl Agent for SlowAgent {
+        async fn execute(&mut self, _project: &mut Project) -> Result<AgentReport, Box<dyn std::error::Error>> {
+            {
+                let mut running_agents = RUNNING_SLOW_AGENTS.lock().unwrap();
+                let mut overlapping_agents = OVERLAPPING_SLOW_AGENTS.lock().unwrap();
+                overlapping_agents.extend(running_agents.iter().map(|&running_agent| (running_agent, self.name)));
+                running_agents.push(self.name);
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            RUNNING_SLOW_AGENTS.lock().unwrap().retain(|&running_agent| running_agent != self.name);
+            Ok(AgentReport::new(&self.attributes.position))
+        }
+
+        fn attributes(&self) -> &AgentAttributes {
+            &self.attributes
+        }
+
+        fn can_run(&self, _project: &Project) -> bool {
+            true
+        }
+
+        fn reset(&mut self) {}
+
+        fn requires(&self) -> Vec<ProjectField> {
+            vec![ProjectField::Description]
+        }
+
+        fn produces(&self) -> Vec<ProjectField> {
+            self.produces.clone()
+        }
+
+        fn prompts_user(&self) -> bool {
+            self.prompts_user
+        }
+    }
+
+    fn slow_agent(name: &'static str, produces: Vec<ProjectField>, prompts_user: bool) -> Box<dyn Agent> {
+        Box::new(SlowAgent {
+            attributes: AgentAttributes {
+                objective: String::new(),
+                position: name.to_string(),
+                status: AgentStatus::Discovery,
+            },
+            name,
+            produces,
+            prompts_user,
+        })
+    }
+
+    // An agent that panics, like one decoding a malformed LLM response used to.
+    #[derive(Debug)]
+    struct PanickingAgent {
+        attributes: AgentAttributes,
+    }
+
+    #[async_trait]
+    impl Agent for PanickingAgent {
+        async fn execute(&mut self, project: &mut Project) -> Result<AgentReport, Box<dyn std::error::Error>> {
+            project.scope = None;
+            panic!("malformed response");
+        }
+
+        fn attributes(&self) -> &AgentAttributes {
+            &self.attributes
+        }
+
+        fn can_run(&self, _project: &Project) -> bool {
+            true
+        }
+
+        fn reset(&mut self) {}
+
+        fn requires(&self) -> Vec<ProjectField> {
+            vec![ProjectField::Description]
+        }
+
+        fn produces(&self) -> Vec<ProjectField> {
+            vec![ProjectField::Scope]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_agent_graph_reports_panicking_agents() {
+        let mut agent_graph = AgentGraph::new(0);
+        agent_graph.add_agent(Box::new(PanickingAgent {
+            attributes: AgentAttributes {
+                objective: String::new(),
+                position: "planner".to_string(),
+                status: AgentStatus::Discovery,
+            },
+        }));
+        agent_graph.add_agent(slow_agent("frontend", vec![ProjectField::FrontendCode], false));
+
+        let mut project = Project::new("build a website that tracks tasks".to_string());
+        agent_graph.execute("Project Manager", &mut project)
+            .await;
+
+        // The panic fails only that agent, while the others still run and report.
+        assert!(!agent_graph.is_successful());
+        assert_eq!(agent_graph.reports.len(), 2);
+        let failed_report = agent_graph.reports
+            .iter()
+            .find(|report| report.position == "planner")
+            .expect("No report for the panicking agent.");
+        assert!(failed_report.failed_checks[0].contains("malformed response"), "{:?}", failed_report.failed_checks);
+        assert_eq!(agent_graph.completed_agents(), vec!["frontend"]);
+        assert!(agent_graph.ready_agents().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_agent_graph_serializes_exclusive_agents() {
+        let mut agent_graph = AgentGraph::new(0);
+        agent_graph.add_agent(slow_agent("backend", vec![ProjectField::BackendCode], false));
+        agent_graph.add_agent(slow_agent("frontend", vec![ProjectField::FrontendCode], false));
+        agent_graph.add_agent(slow_agent("interviewer", vec![ProjectField::Requirements], true));
+        agent_graph.add_agent(slow_agent("reviewer", vec![ProjectField::ReviewIssues], false));
+        assert_eq!(agent_graph.ready_agents(), vec![0, 1, 2, 3]);
+
+        let mut project = Project::new("build a website that tracks tasks".to_string());
+        agent_graph.execute("Project Manager", &mut project)
+            .await;
+
+        // Agents sharing directories or stdin never overlap, while the others still run alongside them.
+        let overlapping_agents = OVERLAPPING_SLOW_AGENTS.lock().unwrap().clone();
+        assert_eq!(overlapping_agents, vec![("backend", "reviewer")]);
+        assert_eq!(agent_graph.completed_agents(), vec!["backend", "frontend", "interviewer", "reviewer"]);
+    }
+
+    #[tokio::test]
+    async fn test_agent_graph() {
+        let mut agent_graph = AgentGraph::new(2);
+        agent_graph.add_agent(architect());
+        agent_graph.add_agent(backend());
+        agent_graph.add_agent(frontend());
+        agent_graph.add_agent(qa());
+        agent_graph.add_agent(reviewer());
+        assert_eq!(agent_graph.dependencies(1), Vec::<usize>::new());
+        assert_eq!(agent_graph.dependencies(3), vec![1]);
+        assert_eq!(agent_graph.ready_agents(), vec![0, 1]);
+
+        let mut project = Project {
+            description: "build a website that tracks tasks".to_string(),
+            requirements: None,
+            scope: None,
+            external_urls: None,
+            backend_code: None,
+            backend_tasks: vec![],
+            api_endpoint_schema: None,
+            server_address: None,
+            frontend_code: None,
+            review_issues: vec![],
+            defects: vec![],
+        };
+        agent_graph.execute("Project Manager", &mut project)
+            .await;
+
+        // QA's defect sends the backend back to work, and its new code is checked again downstream.
+        let mut executed_agents = EXECUTED_AGENTS.lock().unwrap().clone();
+        executed_agents.sort();
+        assert_eq!(executed_agents, vec!["architect", "backend", "backend", "frontend", "frontend", "qa", "qa"]);
+        assert_eq!(project.backend_code.as_deref(), Some("v2"));
+        assert!(project.defects.is_empty());
+
+        // The reviewer never has issues to work from, so it is skipped each time its input changes.
+        let skipped_reports: Vec<&AgentReport> = agent_graph.reports
+            .iter()
+            .filter(|report| !report.warnings.is_empty())
+            .collect();
+        assert_eq!(skipped_reports.len(), 2);
+        assert!(skipped_reports.iter().all(|report| report.position == "reviewer"));
+        assert_eq!(agent_graph.reports.len(), 9);
+        assert_eq!(agent_graph.completed_agents(), vec!["architect", "backend", "frontend", "qa"]);
+
+        // Only the latest report of each agent counts, so the defect QA filed and saw fixed does not fail the run.
+        assert!(agent_graph.reports.iter().any(|report| !report.failed_checks.is_empty()));
+        assert!(agent_graph.is_successful());
+        let mut failed_report = AgentReport::new("frontend");
+        failed_report.add_failed_check("Failed, and its changes were rolled back: no API".to_string());
+        agent_graph.reports.push(failed_report);
+        assert!(!agent_graph.is_successful());
+
+        // A resumed run picks up after the agents that were done.
+        let mut resumed_agent_graph = AgentGraph::new(2);
+        resumed_agent_graph.add_agent(architect());
+        resumed_agent_graph.add_agent(backend());
+        resumed_agent_graph.add_agent(qa());
+        resumed_agent_graph.mark_completed(&["architect".to_string(), "backend".to_string()]);
+        assert_eq!(resumed_agent_graph.ready_agents(), vec![2]);
+    }
+}
@@ -0,0 +1,180 @@
+/*
+ *  agents/manager/agent_registry.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::agent_graph::AgentGraph;
+use super::super::common::traits::Agent;
+use super::super::architect::architect_agent::SolutionArchitectAgent;
+use super::super::backend::backend_agent::BackendDeveloperAgent;
+use super::super::frontend::frontend_agent::FrontendDeveloperAgent;
+use super::super::planner::planning_agent::PlanningAgent;
+use super::super::qa::qa_agent::QaEngineerAgent;
+
+use crate::model::pipeline_configuration::{ AgentOptions, PipelineConfiguration };
+
+use std::collections::BTreeMap;
+
+pub type AgentConstructor = fn(&AgentOptions) -> Box<dyn Agent>;
+
+// Constructor of an agent, with the names of the options it accepts.
+#[derive(Debug)]
+struct RegisteredAgent {
+    option_names: &'static [&'static str],
+    constructor: AgentConstructor,
+}
+
+// Agent constructors by the name pipelines refer to them with.
+#[derive(Debug)]
+pub struct AgentRegistry {
+    constructors: BTreeMap<String, RegisteredAgent>,
+}
+
+impl AgentRegistry {
+    pub fn new() -> Self {
+        Self {
+            constructors: BTreeMap::new(),
+        }
+    }
+
+    // A registry with every agent that ships with the project.
+    pub fn with_default_agents() -> Self {
+        let mut registry = Self::new();
+        registry.register("architect", &[], |_| Box::new(SolutionArchitectAgent::new()));
+        registry.register("planner", &[], |_| Box::new(PlanningAgent::new()));
+        registry.register("backend", &["maximum_bug_count", "maximum_review_rounds", "server_host"], |options| {
+            let mut agent = BackendDeveloperAgent::new();
+            if let Some(maximum_bug_count) = options.maximum_bug_count {
+                agent.maximum_bug_count = maximum_bug_count;
+            }
+            if let Some(maximum_review_rounds) = options.maximum_review_rounds {
+                agent.maximum_review_rounds = maximum_review_rounds;
+            }
+            if let Some(server_host) = &options.server_host {
+                agent.server_host = server_host.clone();
+            }
+            Box::new(agent)
+        });
+        registry.register("frontend", &["maximum_fix_attempts", "api_base_url"], |options| {
+            let mut agent = FrontendDeveloperAgent::new();
+            if let Some(maximum_fix_attempts) = options.maximum_fix_attempts {
+                agent.maximum_fix_attempts = maximum_fix_attempts;
+            }
+            if let Some(api_base_url) = &options.api_base_url {
+                agent.api_base_url = Some(api_base_url.clone());
+            }
+            Box::new(agent)
+        });
+        registry.register("qa", &["server_host"], |options| {
+            let mut agent = QaEngineerAgent::new();
+            if let Some(server_host) = &options.server_host {
+                agent.server_host = server_host.clone();
+            }
+            Box::new(agent)
+        });
+        registry
+    }
+
+    pub fn register(&mut self, name: &str, option_names: &'static [&'static str], constructor: AgentConstructor) {
+        self.constructors.insert(name.to_string(), RegisteredAgent { option_names, constructor });
+    }
+
+    pub fn agent_names(&self) -> Vec<String> {
+        self.constructors.keys().cloned().collect()
+    }
+
+    pub fn create_agent(&self, name: &str, options: &AgentOptions) -> Result<Box<dyn Agent>, String> {
+        let registered_agent = self.constructors
+            .get(name)
+            .ok_or_else(|| format!("Unknown agent \"{}\"; expected one of: {}.", name, self.agent_names().join(", ")))?;
+        let inapplicable_option_names: Vec<&str> = options
+            .set_option_names()
+            .into_iter()
+            .filter(|option_name| !registered_agent.option_names.contains(option_name))
+            .collect();
+        if !inapplicable_option_names.is_empty() {
+            return Err(format!(
+                "Agent \"{}\" does not accept the options {}; it accepts: {}.",
+                name,
+                inapplicable_option_names.join(", "),
+                if registered_agent.option_names.is_empty() { "none".to_string() } else { registered_agent.option_names.join(", ") },
+            ));
+        }
+        Ok((registered_agent.constructor)(options))
+    }
+
+    // Build the agent graph for a pipeline, in the order of its stages.
+    pub fn create_agent_graph(&self, configuration: &PipelineConfiguration) -> Result<AgentGraph, String> {
+        let mut agent_graph = AgentGraph::new(configuration.maximum_feedback_rounds);
+        for stage in &configuration.stages {
+            agent_graph.add_agent(self.create_agent(&stage.agent, &stage.options)?);
+        }
+        Ok(agent_graph)
+    }
+}
+
+impl Default for AgentRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::pipeline_configuration::PipelineStage;
+
+    #[test]
+    fn test_agent_registry() {
+        let registry = AgentRegistry::with_default_agents();
+        assert_eq!(registry.agent_names(), vec!["architect", "backend", "frontend", "planner", "qa"]);
+
+        let options = AgentOptions {
+            maximum_fix_attempts: Some(1),
+            ..Default::default()
+        };
+        let agent = registry.create_agent("frontend", &options)
+            .expect("Failed to create frontend agent.");
+        assert_eq!(agent.attributes().position, "Frontend Developer");
+
+        let configuration = PipelineConfiguration {
+            stages: vec![
+                PipelineStage { agent: "backend".to_string(), options: AgentOptions::default() },
+                PipelineStage { agent: "qa".to_string(), options: AgentOptions::default() },
+            ],
+            maximum_feedback_rounds: 1,
+        };
+        let agent_graph = registry.create_agent_graph(&configuration)
+            .expect("Failed to create the backend and QA pipeline.");
+        assert!(agent_graph.completed_agents().is_empty());
+
+        let options = AgentOptions {
+            maximum_bug_count: Some(3),
+            api_base_url: Some("http://localhost:8080".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            registry.create_agent("qa", &options).err(),
+            Some("Agent \"qa\" does not accept the options maximum_bug_count, api_base_url; it accepts: server_host.".to_string()),
+        );
+        assert!(registry.create_agent("planner", &options).is_err());
+
+        let configuration = PipelineConfiguration {
+            stages: vec![
+                PipelineStage { agent: "backend".to_string(), options: AgentOptions::default() },
+                PipelineStage { agent: "tester".to_string(), options: AgentOptions::default() },
+            ],
+            maximum_feedback_rounds: 1,
+        };
+        assert_eq!(
+            registry.create_agent_graph(&configuration).err(),
+            Some("Unknown agent \"tester\"; expected one of: architect, backend, frontend, planner, qa.".to_string()),
+        );
+    }
+}
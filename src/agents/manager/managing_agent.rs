@@ -7,71 +7,128 @@
  *
  */
 
-use super::managing_ai_functions::convert_user_input_to_goal;
+use super::agent_graph::AgentGraph;
+use super::agent_registry::AgentRegistry;
+use super::managing_ai_functions::{ convert_user_input_to_goal, print_clarifying_questions };
 use super::super::common::attributes::{ AgentAttributes, AgentStatus };
-use super::super::common::traits::Agent;
-use super::super::architect::architect_agent::SolutionArchitectAgent;
-use super::super::backend::backend_agent::BackendDeveloperAgent;
 
+use crate::model::pipeline_configuration::PipelineConfiguration;
 use crate::model::project::Project;
-use crate::utilities::ai_tasks::ai_task_request;
+use crate::model::requirements::{ Clarification, Requirements };
+use crate::model::run_state::RunState;
+use crate::utilities::ai_tasks::{ ai_task_request, ai_task_request_decoded };
+use crate::utilities::command_line::CommandLine;
+
+// Number of clarifying questions the user is asked at most.
+const MAXIMUM_CLARIFYING_QUESTIONS: usize = 5;
 
 #[derive(Debug)]
 pub struct ManagingAgent {
     pub attributes: AgentAttributes,
     pub project: Project,
-    pub agents: Vec<Box<dyn Agent>>,
+    pub agent_graph: AgentGraph,
 }
 
 impl ManagingAgent {
-    pub async fn new(user_request: String) -> Result<Self, Box<dyn std::error::Error>> {
-        let position = "Project Manager".to_string();
-        let attributes = AgentAttributes {
+    fn attributes() -> AgentAttributes {
+        AgentAttributes {
             objective: "Manage agents who are building an excellent website for the user".to_string(),
-            position: position.clone(),
+            position: "Project Manager".to_string(),
             status: AgentStatus::Discovery,
-        };
+        }
+    }
+
+    pub async fn new(
+        user_request: String, 
+        pipeline_configuration: &PipelineConfiguration, 
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let attributes = Self::attributes();
+        let position = attributes.position.clone();
 
+        // Check the pipeline before spending any LLM calls.
+        let agent_graph = AgentRegistry::with_default_agents()
+            .create_agent_graph(pipeline_configuration)?;
+
+        let requirements = Self::clarify_requirements(&position, user_request).await?;
         let description: String = ai_task_request(
-            user_request, 
+            requirements.to_document(), 
             &position, 
             get_function_string!(convert_user_input_to_goal), 
             convert_user_input_to_goal,
         ).await;
 
-        let agents: Vec<Box<dyn Agent>> = vec![];
         let project = Project {
-            description,
-            scope: None,
-            external_urls: None,
-            backend_code: None,
-            api_endpoint_schema: None,
+            requirements: Some(requirements),
+            ..Project::new(description)
         };
 
         Ok(Self {
             attributes, 
             project,
-            agents,
+            agent_graph,
+        })
+    }
+
+    // Pick up a run where it stopped, without asking the user again; completed agents only run when their inputs change.
+    pub fn resume(
+        run_state: RunState, 
+        pipeline_configuration: &PipelineConfiguration, 
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut agent_graph = AgentRegistry::with_default_agents()
+            .create_agent_graph(pipeline_configuration)?;
+        agent_graph.mark_completed(&run_state.completed_agents);
+
+        Ok(Self {
+            attributes: Self::attributes(), 
+            project: run_state.project,
+            agent_graph,
         })
     }
 
-    fn add_agent(&mut self, agent: Box<dyn Agent>) {
-        self.agents.push(agent);
+    // The project so far and the agents done with it, to save and resume from.
+    pub fn run_state(&self) -> RunState {
+        RunState {
+            project: self.project.clone(),
+            completed_agents: self.agent_graph.completed_agents(),
+        }
     }
 
-    fn create_agents(&mut self) {
-        self.add_agent(Box::new(SolutionArchitectAgent::new()));
-        self.add_agent(Box::new(BackendDeveloperAgent::new()));
+    // Ask the user the questions that matter most for the build, unless nobody is at the terminal.
+    async fn clarify_requirements(position: &str, user_request: String) -> Result<Requirements, String> {
+        if !CommandLine::is_interactive() {
+            return Ok(Requirements {
+                request: user_request,
+                clarifications: vec![],
+            });
+        }
+
+        let questions: Vec<String> = ai_task_request_decoded(
+            user_request.clone(), 
+            position, 
+            get_function_string!(print_clarifying_questions), 
+            print_clarifying_questions, 
+        ).await?;
+
+        let clarifications = questions
+            .into_iter()
+            .take(MAXIMUM_CLARIFYING_QUESTIONS)
+            .map(|question| {
+                let answer = CommandLine::get_user_response(&format!("{} (press Enter to skip)", question));
+                Clarification {
+                    question,
+                    answer,
+                }
+            })
+            .collect();
+        Ok(Requirements {
+            request: user_request,
+            clarifications,
+        })
     }
 
     pub async fn execute(&mut self) {
-        self.create_agents();
-        for agent in &mut self.agents {
-            let agent_result = agent.execute(&mut self.project)
-                .await;
-            _ = agent_result;
-        }
-        _ = self.attributes.objective;
+        self.agent_graph.execute(&self.attributes.position, &mut self.project)
+            .await;
     }
 }
 
@@ -86,7 +143,7 @@ mod tests {
     #[allow(dead_code)]
     async fn test_managing_agent() {
         let user_request = "need a full stack app that fetches and tracks my fitness progress. Needs to include timezone info from the web.";
-        let mut managing_agent = ManagingAgent::new(user_request.to_string())
+        let mut managing_agent = ManagingAgent::new(user_request.to_string(), &PipelineConfiguration::default())
             .await
             .expect("Error creating managing agent.");
         managing_agent.execute()
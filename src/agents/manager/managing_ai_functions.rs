@@ -22,3 +22,17 @@ pub fn convert_user_input_to_goal(_user_request: &str) {
     ///   OUTPUT = "build a website that fetches and stores crypto price data within a supabase setup including a frontend UI to fetch the data."
     println!(OUTPUT)
 }
+
+#[ai_function]
+pub fn print_clarifying_questions(_user_request: &str) {
+    /// Input: Takes in a user request to build a website
+    /// Function: Lists the few questions whose answers would change the most how the website is built, such as whether users log in, whether data must persist, which external data sources to use, and how many users to expect
+    /// Important: Asks at most 5 short questions, and none whose answer is already in the user request
+    /// Output: Prints ONLY a JSON list of questions, nothing else:
+    ///   ["question1", "question2", ...]
+    /// Example:
+    ///   user_request = "I need a simple TODO app"
+    ///   prints:
+    ///   ["Do users need to log in to see only their own tasks?", "Should tasks be kept after the server restarts?"]
+    println!(OUTPUT)
+}
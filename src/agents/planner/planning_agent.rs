@@ -0,0 +1,120 @@
+/*
+ *  agents/planner/planning_agent.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::planning_ai_functions::print_backend_tasks;
+use super::super::common::attributes::{ AgentAttributes, AgentStatus };
+use super::super::common::report::AgentReport;
+use super::super::common::traits::Agent;
+
+use crate::model::backend_task::BackendTask;
+use crate::model::project::Project;
+use crate::model::project_field::ProjectField;
+use crate::utilities::ai_tasks::ai_task_request_decoded;
+use crate::utilities::event_bus::EventBus;
+
+use async_trait::async_trait;
+
+// Planner
+#[derive(Debug)]
+pub struct PlanningAgent {
+    pub attributes: AgentAttributes,
+}
+
+impl PlanningAgent {
+    pub fn new() -> Self {
+        let attributes = AgentAttributes {
+            objective: "Decomposes the project goal into an ordered list of concrete backend tasks.".to_string(),
+            position: "Planner".to_string(),
+            status: AgentStatus::Discovery,
+        };
+        Self {
+            attributes
+        }
+    }
+
+    async fn plan_backend_tasks(&mut self, project: &mut Project) -> Result<(), String> {
+        let message_context = format!(
+            "PROJECT_DESCRIPTION: {} \n PROJECT_SCOPE: {:?} \n EXTERNAL_URLS: {:?} \n", 
+            project.description, 
+            project.scope, 
+            project.external_urls, 
+        );
+
+        let backend_tasks: Vec<BackendTask> = ai_task_request_decoded(
+            message_context, 
+            &self.attributes.position, 
+            get_function_string!(print_backend_tasks), 
+            print_backend_tasks, 
+        ).await?;
+
+        for (index, backend_task) in backend_tasks.iter().enumerate() {
+            EventBus::publish_generation_message(
+                &self.attributes.position, 
+                format!("Task {}: {}", index + 1, backend_task.summary()).as_str(), 
+            );
+        }
+        project.backend_tasks = backend_tasks;
+        Ok(())
+    }
+}
+
+impl Default for PlanningAgent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Agent for PlanningAgent {
+    async fn execute(&mut self, project: &mut Project) -> Result<AgentReport, Box<dyn std::error::Error>> {
+        let mut report = AgentReport::new(&self.attributes.position);
+        while self.attributes.status != AgentStatus::Finished {
+            match self.attributes.status {
+
+                AgentStatus::Discovery | AgentStatus::Working | AgentStatus::UnitTesting => {
+                    self.plan_backend_tasks(project).await?;
+                    self.attributes.set_status(AgentStatus::Finished);
+                },
+
+                AgentStatus::Finished => {},
+
+            }
+        }
+
+        report.add_artifact(format!("{} backend tasks", project.backend_tasks.len()));
+
+        Ok(report)
+    }
+
+    fn attributes(&self) -> &AgentAttributes {
+        &self.attributes
+    }
+
+    fn can_run(&self, project: &Project) -> bool {
+        !project.description.is_empty()
+    }
+
+    fn reset(&mut self) {
+        self.attributes.set_status(AgentStatus::Discovery);
+    }
+
+    fn requires(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::Description,
+            ProjectField::Scope,
+            ProjectField::ExternalUrls,
+        ]
+    }
+
+    fn produces(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::BackendTasks,
+        ]
+    }
+}
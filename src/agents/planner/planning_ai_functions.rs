@@ -0,0 +1,29 @@
+/*
+ *  agents/planner/planning_ai_functions.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use ai_functions::ai_function;
+
+#[ai_function]
+pub fn print_backend_tasks(_project_description_and_scope: &str) {
+    /// INPUT: Takes in a PROJECT_DESCRIPTION, the PROJECT_SCOPE and the EXTERNAL_URLS of a website backend
+    /// FUNCTION: Decomposes the backend build into an ordered list of concrete tasks, each small enough to implement and test on its own
+    /// IMPORTANT: Each task has a kind, one of:
+    ///   "entity": a data type stored in the JSON database
+    ///   "endpoint": one or a few REST endpoints acting on an entity
+    ///   "integration": fetching data from one of the EXTERNAL_URLS
+    ///   "auth": user registration, login or logout
+    /// IMPORTANT: Orders tasks so each only depends on earlier ones, entities first. Uses at most 8 tasks.
+    /// OUTPUT: Prints ONLY a JSON list in the following format, nothing else:
+    ///   [
+    ///     { "title": "Task entity", "kind": "entity", "description": "A task has an id, a name and a completed flag." },
+    ///     { "title": "Task CRUD endpoints", "kind": "endpoint", "description": "POST /task, GET /task, GET /task/{id}, PUT /task and DELETE /task/{id}." }
+    ///   ]
+    /// Do NOT surround the JSON with triple backticks.
+    println!(OUTPUT)
+}
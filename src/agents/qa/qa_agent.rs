@@ -0,0 +1,232 @@
+/*
+ *  agents/qa/qa_agent.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::qa_ai_functions::print_test_scenarios;
+use super::super::common::attributes::{ AgentAttributes, AgentStatus };
+use super::super::common::report::AgentReport;
+use super::super::common::traits::Agent;
+
+use crate::model::defect::Defect;
+use crate::model::project::Project;
+use crate::model::project_field::ProjectField;
+use crate::model::test_scenario::TestScenario;
+use crate::utilities::ai_tasks::ai_task_request_decoded;
+use crate::utilities::backend_server::BackendServer;
+use crate::utilities::event_bus::EventBus;
+use crate::utilities::networking::find_available_port;
+use crate::utilities::scenario_testing::run_test_scenario;
+
+use async_trait::async_trait;
+use reqwest::Client;
+use std::time::Duration;
+
+const DEFAULT_SERVER_HOST: &str = "127.0.0.1";
+const DEFAULT_SERVER_STARTUP_DEADLINE: Duration = Duration::from_secs(30);
+
+// QA Engineer
+#[derive(Debug)]
+pub struct QaEngineerAgent {
+    pub attributes: AgentAttributes,
+    pub test_scenarios: Vec<TestScenario>,
+    pub server_startup_deadline: Duration,
+    pub server_host: String,
+}
+
+impl QaEngineerAgent {
+    pub fn new() -> Self {
+        let attributes = AgentAttributes {
+            objective: "Writes and runs black-box test scenarios against the backend, independently of its developer.".to_string(),
+            position: "QA Engineer".to_string(),
+            status: AgentStatus::Discovery,
+        };
+
+        Self {
+            attributes,
+            test_scenarios: vec![],
+            server_startup_deadline: DEFAULT_SERVER_STARTUP_DEADLINE,
+            server_host: DEFAULT_SERVER_HOST.to_string(),
+        }
+    }
+
+    // Write scenarios from what the project should do, not from the code that was written.
+    async fn write_test_scenarios(&mut self, project: &Project) -> Result<(), String> {
+        let message_context = format!(
+            "PROJECT_DESCRIPTION: {} \n PROJECT_SCOPE: {:?} \n API_ENDPOINTS: {} \n", 
+            project.description, 
+            project.scope, 
+            serde_json::to_string(&project.api_endpoint_schema).expect("Failed to encode API endpoints."), 
+        );
+
+        self.test_scenarios = ai_task_request_decoded(
+            message_context, 
+            &self.attributes.position, 
+            get_function_string!(print_test_scenarios), 
+            print_test_scenarios, 
+        ).await?;
+        Ok(())
+    }
+
+    // Launch the built backend, run every scenario against it, and file what fails onto the project.
+    // Defects of the previous round are dropped first, so only those the current code still has are reported.
+    async fn run_test_scenarios(&mut self, project: &mut Project) -> Result<(), Box<dyn std::error::Error>> {
+        project.defects.clear();
+        EventBus::publish_unit_test_message(
+            &self.attributes.position, 
+            "QA Testing: Starting web server…", 
+        );
+        let server_port = find_available_port(&self.server_host)?;
+        let base_url = format!("http://{}:{}", self.server_host, server_port);
+
+        let mut backend_server = BackendServer::launch_backend_binary("qa", &self.server_host, server_port)?;
+
+        if let Err(error) = backend_server
+            .wait_until_ready(&self.server_host, server_port, self.server_startup_deadline)
+            .await
+        {
+            backend_server.stop();
+            project.defects.push(Defect::server_startup(base_url, &error.to_string()));
+            return Ok(());
+        }
+
+        let client = Client::builder()
+            .timeout(Duration::from_secs(5))
+            .build()?;
+        let mut failed_scenario_count = 0;
+        for scenario in &self.test_scenarios {
+            match run_test_scenario(&client, &base_url, scenario).await {
+                None => EventBus::publish_unit_test_message(
+                    &self.attributes.position, 
+                    format!("PASS {}", scenario.name).as_str(), 
+                ),
+                Some(defect) => {
+                    EventBus::publish_issue_message(
+                        &self.attributes.position, 
+                        format!("FAIL {}", defect.summary()).as_str(), 
+                    );
+                    project.defects.push(defect);
+                    failed_scenario_count += 1;
+                },
+            }
+        }
+        backend_server.stop();
+
+        let summary = format!(
+            "QA Testing: {} of {} scenarios passed.", 
+            self.test_scenarios.len() - failed_scenario_count, 
+            self.test_scenarios.len(), 
+        );
+        EventBus::publish_unit_test_message(&self.attributes.position, summary.as_str());
+        Ok(())
+    }
+}
+
+impl Default for QaEngineerAgent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Agent for QaEngineerAgent {
+    async fn execute(&mut self, project: &mut Project) -> Result<AgentReport, Box<dyn std::error::Error>> {
+        let mut report = AgentReport::new(&self.attributes.position);
+        while self.attributes.status != AgentStatus::Finished {
+            match self.attributes.status {
+
+                AgentStatus::Discovery => {
+                    if !self.can_run(project) {
+                        EventBus::publish_issue_message(
+                            &self.attributes.position, 
+                            "QA Testing: There is no backend to test yet.", 
+                        );
+                        self.attributes.set_status(AgentStatus::Finished);
+                        continue;
+                    }
+                    self.write_test_scenarios(project).await?;
+                    self.attributes.set_status(AgentStatus::UnitTesting);
+                },
+
+                AgentStatus::Working | AgentStatus::UnitTesting => {
+                    self.run_test_scenarios(project).await?;
+                    self.attributes.set_status(AgentStatus::Finished);
+                },
+
+                AgentStatus::Finished => {},
+
+            }
+        }
+
+        report.add_artifact(format!("{} test scenarios", self.test_scenarios.len()));
+        for defect in &project.defects {
+            report.add_failed_check(defect.summary());
+        }
+
+        Ok(report)
+    }
+
+    fn attributes(&self) -> &AgentAttributes {
+        &self.attributes
+    }
+
+    fn can_run(&self, project: &Project) -> bool {
+        project.backend_code.is_some() && project.api_endpoint_schema.is_some()
+    }
+
+    fn reset(&mut self) {
+        self.attributes.set_status(AgentStatus::Discovery);
+        self.test_scenarios.clear();
+    }
+
+    fn requires(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::Description,
+            ProjectField::Scope,
+            ProjectField::BackendCode,
+            ProjectField::ApiEndpointSchema,
+        ]
+    }
+
+    fn produces(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::Defects,
+        ]
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utilities::backend_code_persistence::BackendCodePersistence;
+
+    // Disable this test for global `cargo test` commands.
+    //#[tokio::test]
+    #[allow(dead_code)]
+    async fn test_qa_engineer_agent() {
+        let mut agent = QaEngineerAgent::new();
+        let mut project = Project {
+            description: "build a website that tracks tasks".to_string(),
+            requirements: None,
+            scope: None,
+            external_urls: None,
+            backend_code: Some(BackendCodePersistence::read_executable_main_contents()),
+            backend_tasks: vec![],
+            api_endpoint_schema: Some(vec![]),
+            server_address: None,
+            frontend_code: None,
+            review_issues: vec![],
+            defects: vec![],
+        };
+        agent.execute(&mut project)
+            .await
+            .expect("Failed to execute QA Engineer agent.");
+        dbg!(project.defects);
+    }
+}
@@ -0,0 +1,32 @@
+/*
+ *  agents/qa/qa_ai_functions.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use ai_functions::ai_function;
+
+#[ai_function]
+pub fn print_test_scenarios(_project_description_scope_and_api_endpoints: &str) {
+    /// INPUT: Takes in a PROJECT_DESCRIPTION, the PROJECT_SCOPE and the API_ENDPOINTS schema of a website backend
+    /// FUNCTION: Acts as a QA engineer who has NOT seen the code. Writes black-box test scenarios which check that the backend does what the PROJECT_DESCRIPTION asks for.
+    /// IMPORTANT: Each scenario is a short user journey of HTTP requests against the API_ENDPOINTS only.
+    /// IMPORTANT: Includes error cases, such as reading a resource which does not exist or sending an invalid body, with the status codes a correct backend returns.
+    /// IMPORTANT: In a path, `{id}` stands for the `id` field of the last JSON response which had one.
+    /// OUTPUT: Prints ONLY a JSON list of scenarios in the following format, nothing else:
+    ///   [
+    ///     {
+    ///       "name": "Create and read a task",
+    ///       "description": "A user creates a task, then reads it back.",
+    ///       "steps": [
+    ///         { "method": "POST", "path": "/task", "request_body": { "id": 1, "name": "Groceries", "completed": false }, "expected_status_codes": [200, 201] },
+    ///         { "method": "GET", "path": "/task/{id}", "request_body": null, "expected_status_codes": [200] }
+    ///       ]
+    ///     }
+    ///   ]
+    /// Do NOT surround the JSON with triple backticks.
+    println!(OUTPUT)
+}
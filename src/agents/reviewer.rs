@@ -0,0 +1,11 @@
+/*
+ *  agents/reviewer.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+pub mod code_review_agent;
+pub mod code_review_ai_functions;
@@ -0,0 +1,127 @@
+/*
+ *  agents/reviewer/code_review_agent.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::code_review_ai_functions::print_code_review;
+use super::super::common::attributes::{ AgentAttributes, AgentStatus };
+use super::super::common::report::AgentReport;
+use super::super::common::traits::Agent;
+
+use crate::model::project::Project;
+use crate::model::project_field::ProjectField;
+use crate::model::review_issue::ReviewIssue;
+use crate::utilities::ai_tasks::ai_task_request_decoded;
+use crate::utilities::event_bus::EventBus;
+
+use async_trait::async_trait;
+
+// Code Reviewer
+#[derive(Debug)]
+pub struct CodeReviewAgent {
+    pub attributes: AgentAttributes,
+}
+
+impl CodeReviewAgent {
+    pub fn new() -> Self {
+        let attributes = AgentAttributes {
+            objective: "Reviews generated backend code for correctness, security, validation and concurrency issues.".to_string(),
+            position: "Code Reviewer".to_string(),
+            status: AgentStatus::Discovery,
+        };
+        Self {
+            attributes
+        }
+    }
+
+    async fn review_backend_code(&mut self, project: &mut Project) -> Result<(), String> {
+        let message_context = format!(
+            "PROJECT_DESCRIPTION: {} \n PROJECT_SCOPE: {:?} \n BACKEND_CODE: {} \n", 
+            project.description, 
+            project.scope, 
+            project.backend_code.as_deref().unwrap_or_default(), 
+        );
+
+        let review_issues: Vec<ReviewIssue> = ai_task_request_decoded(
+            message_context, 
+            &self.attributes.position, 
+            get_function_string!(print_code_review), 
+            print_code_review, 
+        ).await?;
+
+        for review_issue in &review_issues {
+            EventBus::publish_unit_test_message(
+                &self.attributes.position, 
+                format!("Code Review: {}", review_issue.summary()).as_str(), 
+            );
+        }
+        project.review_issues = review_issues;
+        Ok(())
+    }
+}
+
+impl Default for CodeReviewAgent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Agent for CodeReviewAgent {
+    async fn execute(&mut self, project: &mut Project) -> Result<AgentReport, Box<dyn std::error::Error>> {
+        let mut report = AgentReport::new(&self.attributes.position);
+        while self.attributes.status != AgentStatus::Finished {
+            match self.attributes.status {
+
+                AgentStatus::Discovery | AgentStatus::Working | AgentStatus::UnitTesting => {
+                    if self.can_run(project) {
+                        self.review_backend_code(project).await?;
+                    } else {
+                        project.review_issues.clear();
+                    }
+                    self.attributes.set_status(AgentStatus::Finished);
+                },
+
+                AgentStatus::Finished => {},
+
+            }
+        }
+
+        report.add_artifact(format!("{} review issues", project.review_issues.len()));
+        for review_issue in project.review_issues.iter().filter(|review_issue| review_issue.is_blocking()) {
+            report.add_failed_check(review_issue.summary());
+        }
+
+        Ok(report)
+    }
+
+    fn attributes(&self) -> &AgentAttributes {
+        &self.attributes
+    }
+
+    fn can_run(&self, project: &Project) -> bool {
+        project.backend_code.is_some()
+    }
+
+    fn reset(&mut self) {
+        self.attributes.set_status(AgentStatus::Discovery);
+    }
+
+    fn requires(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::Description,
+            ProjectField::Scope,
+            ProjectField::BackendCode,
+        ]
+    }
+
+    fn produces(&self) -> Vec<ProjectField> {
+        vec![
+            ProjectField::ReviewIssues,
+        ]
+    }
+}
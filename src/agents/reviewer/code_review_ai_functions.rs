@@ -0,0 +1,36 @@
+/*
+ *  agents/reviewer/code_review_ai_functions.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use ai_functions::ai_function;
+
+#[ai_function]
+pub fn print_code_review(_project_description_and_backend_code: &str) {
+    /// INPUT: Takes in a PROJECT_DESCRIPTION, the PROJECT_SCOPE and the BACKEND_CODE of an actix-web server written for it
+    /// FUNCTION: Acts as a senior Rust reviewer. Finds real issues in the BACKEND_CODE, in these categories:
+    ///   correctness: handlers which do not do what the PROJECT_DESCRIPTION asks, wrong status codes, lost data
+    ///   security: plaintext passwords or password comparison, secrets in code, injection, unbounded input
+    ///   validation: missing checks on request bodies and path parameters
+    ///   concurrency: misuse of the shared Mutex, such as holding the lock across slow work or panicking while holding it
+    ///   performance: needless work on every request
+    ///   maintainability: code which is hard to change safely
+    /// IMPORTANT: Uses severity "blocking" only for issues which must be fixed before the code is run, "major" for serious issues, and "minor" otherwise.
+    /// IMPORTANT: Does not report issues which are not in the code. Prints an empty list if the code has no issues.
+    /// OUTPUT: Prints ONLY a JSON list in the following format, nothing else:
+    ///   [
+    ///     {
+    ///       "category": "security",
+    ///       "severity": "blocking",
+    ///       "location": "login",
+    ///       "description": "Passwords are stored and compared in plaintext.",
+    ///       "suggestion": "Store a salted hash of the password and compare hashes."
+    ///     }
+    ///   ]
+    /// Do NOT surround the JSON with triple backticks.
+    println!(OUTPUT)
+}
@@ -7,12 +7,12 @@
  *
  */
 
-use crate::api::open_ai::response::APIResponse;
+use crate::api::open_ai::response::{ APIResponse, APIUsage };
 
 use dotenv::dotenv;
 use reqwest::Client;
 use reqwest::header::{ HeaderMap, HeaderValue };
-use serde::Serialize;
+use serde::{ Deserialize, Serialize };
 use std::env;
 
 /// The role for a message to GPT.
@@ -32,7 +32,7 @@ pub struct Message {
 }
 
 /// The model to use for the OpenAI API.
-#[derive(Debug, Serialize, Clone, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[allow(dead_code, non_camel_case_types)]
 pub enum Model {
     #[serde(rename = "gpt-5.2")]
@@ -47,6 +47,31 @@ pub enum Model {
     GPT_5_Nano,
 }
 
+impl Model {
+    /// Finds the model with an OpenAI name, such as `gpt-5-mini`.
+    pub fn parse(name: &str) -> Result<Self, String> {
+        serde_json::from_value(serde_json::Value::String(name.to_string()))
+            .map_err(|_| format!("Unknown model \"{}\"; expected gpt-5.2, gpt-5.2-pro, gpt-5, gpt-5-mini or gpt-5-nano.", name))
+    }
+
+    /// The prices of prompt and completion tokens, in US dollars per million, as listed by OpenAI.
+    pub fn token_prices(&self) -> (f64, f64) {
+        match self {
+            Self::GPT_5_2 => (1.75, 14.0),
+            Self::GPT_5_2_Pro => (21.0, 168.0),
+            Self::GPT_5 => (1.25, 10.0),
+            Self::GPT_5_Mini => (0.25, 2.0),
+            Self::GPT_5_Nano => (0.05, 0.4),
+        }
+    }
+
+    /// The cost of the tokens used by a call, in US dollars.
+    pub fn cost(&self, usage: &APIUsage) -> f64 {
+        let (prompt_token_price, completion_token_price) = self.token_prices();
+        (usage.prompt_tokens as f64 * prompt_token_price + usage.completion_tokens as f64 * completion_token_price) / 1_000_000.0
+    }
+}
+
 /// A chat completion for the OpenAI API.
 #[derive(Debug, Serialize, Clone)]
 pub struct ChatCompletion {
@@ -55,11 +80,11 @@ pub struct ChatCompletion {
     pub temperature: f32,
 }
 
-/// Calls OpenAI API with messages for a GPT model.
+/// Calls OpenAI API with messages for a GPT model, returning the reply and the tokens it used.
 pub async fn call_gpt(
     model: Model,
     messages: Vec<Message>,
-) -> Result<String, Box<dyn std::error::Error + Send>> {
+) -> Result<(String, Option<APIUsage>), Box<dyn std::error::Error + Send>> {
 
     // Extract API Key information.
     dotenv().ok();
@@ -98,8 +123,8 @@ pub async fn call_gpt(
 
     // Create chat completion.
     let chat_completion = ChatCompletion {
-        model: model,
-        messages: messages,
+        model,
+        messages,
         temperature: 0.1,
     };
 
@@ -123,7 +148,7 @@ pub async fn call_gpt(
         })?;
     
     // Return response.
-    Ok(response.choices[0].message.content.clone())
+    Ok((response.choices[0].message.content.clone(), response.usage))
 }
 
 
@@ -147,13 +172,11 @@ mod tests {
         let response = call_gpt(Model::GPT_5_2, messages)
             .await;
         match response {
-            Ok(response_string) => {
-                dbg!(response_string);
-                assert!(true);
+            Ok((response_string, usage)) => {
+                dbg!(response_string, usage);
             },
             Err(error) => {
-                println!("Failed to call GPT with error: {:?}", error);
-                assert!(false);
+                panic!("Failed to call GPT with error: {:?}", error);
             }
         }
     }
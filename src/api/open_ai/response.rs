@@ -7,7 +7,7 @@
  *
  */
 
-use serde::Deserialize;
+use serde::{ Deserialize, Serialize };
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct APIMessage {
@@ -19,7 +19,16 @@ pub struct APIChoice {
     pub message: APIMessage,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct APIUsage {
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct APIResponse {
     pub choices: Vec<APIChoice>,
+    #[serde(default)]
+    pub usage: Option<APIUsage>,
 }
\ No newline at end of file
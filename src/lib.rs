@@ -0,0 +1,21 @@
+/*
+ *  lib.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+#[macro_export]
+macro_rules! get_function_string {
+    ($func: ident) => {
+        stringify!($func)
+    };
+}
+
+#[macro_use]
+pub mod api;
+pub mod agents;
+pub mod model;
+pub mod utilities;
@@ -7,28 +7,239 @@
  *
  */
 
-#[macro_export]
-macro_rules! get_function_string {
-    ($func: ident) => {
-        stringify!($func)
+use rust_auto_gpt_coder::agents::backend::backend_agent::BackendDeveloperAgent;
+use rust_auto_gpt_coder::agents::manager::managing_agent::ManagingAgent;
+use rust_auto_gpt_coder::model::pipeline_configuration::PipelineConfiguration;
+use rust_auto_gpt_coder::model::project::Project;
+use rust_auto_gpt_coder::model::run_state::RunState;
+use rust_auto_gpt_coder::utilities::ai_tasks::select_model;
+use rust_auto_gpt_coder::utilities::backend_code_persistence::BackendCodePersistence;
+use rust_auto_gpt_coder::utilities::backend_server::BackendServer;
+use rust_auto_gpt_coder::utilities::command_line::CommandLine;
+use rust_auto_gpt_coder::utilities::command_line_arguments::{ CommandLineArguments, CommandLineCommand };
+use rust_auto_gpt_coder::utilities::event_bus::{ AgentEvent, EventBus, JsonLinesEventSink, TerminalEventSink };
+use rust_auto_gpt_coder::utilities::interruption::{ INTERRUPTED_EXIT_CODE, Interruption };
+use rust_auto_gpt_coder::utilities::logging::{ LoggingConfiguration, TracingEventSink };
+use rust_auto_gpt_coder::utilities::run_directory::RunDirectory;
+use rust_auto_gpt_coder::utilities::run_report::{ RunEventCollector, RunReport };
+use rust_auto_gpt_coder::utilities::transcripts::Transcript;
+
+use clap::Parser;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::{ SystemTime, UNIX_EPOCH };
+use tracing::Instrument;
+
+// A step of the backend agent that runs on its own, on an existing backend project.
+enum BackendStep {
+    Fix,
+    Test,
+    Schema,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let arguments = CommandLineArguments::parse();
+
+    let mut logging_configuration = LoggingConfiguration::from_environment()
+        .unwrap_or_else(|error| {
+            eprintln!("{} Using the default logging configuration.", error);
+            LoggingConfiguration::default()
+        });
+    logging_configuration.apply_verbosity(arguments.verbose, arguments.quiet);
+    if let Err(error) = logging_configuration.initialize() {
+        eprintln!("{}", error);
+    }
+
+    if let Some(model) = arguments.model {
+        select_model(model);
+    }
+    RunDirectory::set_runs_directory(arguments.output_dir);
+    if arguments.yes {
+        CommandLine::approve_generated_code();
+    }
+
+    let command = arguments.command
+        .unwrap_or(CommandLineCommand::New { prompt: vec![] });
+    let result = match command {
+        CommandLineCommand::Transcript { run_id, step } => Transcript::browse(run_id.as_deref(), step)
+            .map(|text| println!("{}", text)),
+        CommandLineCommand::Report { run_dir } => write_report(&run_dir),
+        command => run(command, &arguments.config, arguments.quiet).await,
     };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{}", error);
+            if Interruption::shared().is_interrupted() {
+                ExitCode::from(INTERRUPTED_EXIT_CODE)
+            } else {
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+// Write the report of a past run again, from the state and events saved in its run directory.
+fn write_report(run_directory: &Path) -> Result<(), String> {
+    let run_state = RunState::load(&RunDirectory::get_state_file_path(run_directory))?;
+    let events = JsonLinesEventSink::read_events(&RunDirectory::get_event_log_path(run_directory))
+        .unwrap_or_else(|error| {
+            eprintln!("No events for this run, so the report only covers its state: {}", error);
+            vec![]
+        });
+    let report_path = RunReport::write(run_directory, &run_state.project, &events)
+        .map_err(|error| format!("Unable to write the run report: {}", error))?;
+    println!("Run report: {}", report_path.display());
+    Ok(())
 }
 
-#[macro_use]
-mod api;
-mod agents;
-mod model;
-mod utilities;
+// Run agents, saving the run's transcript, state, events and report in its own directory.
+async fn run(command: CommandLineCommand, pipeline_configuration_path: &Path, is_quiet: bool) -> Result<(), String> {
+    BackendServer::install_interrupt_handler();
+    EventBus::subscribe(Arc::new(TerminalEventSink { is_quiet }));
+    EventBus::subscribe(Arc::new(TracingEventSink {}));
 
-use agents::manager::managing_agent::ManagingAgent;
-use utilities::command_line::CommandLine;
+    // Every log record of this run carries its identifier.
+    let run_started_at = SystemTime::now();
+    let run_id = run_started_at
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis());
+    let run_directory = RunDirectory::get_run_directory(&run_id.to_string());
+    let run_event_log_path = RunDirectory::get_event_log_path(&run_directory);
+    match JsonLinesEventSink::create(&run_event_log_path) {
+        Ok(event_log) => EventBus::subscribe(Arc::new(event_log)),
+        Err(error) => tracing::warn!(path = %run_event_log_path.display(), %error, "unable to open run event log"),
+    }
+    let run_event_collector = Arc::new(RunEventCollector::new());
+    EventBus::subscribe(run_event_collector.clone());
 
-#[tokio::main]
-async fn main() {
-    let user_request = CommandLine::get_user_response("What website are we building today?");
-    let mut managing_agent = ManagingAgent::new(user_request)
-        .await
-        .expect("Error creating managing agent");
+    let run_span = tracing::info_span!("run", run_id);
+    if let Err(error) = Transcript::start(&run_id.to_string()) {
+        tracing::warn!(%error, "unable to save the transcript of this run");
+    }
+    let execution = execute_command(command, pipeline_configuration_path, &run_directory)
+        .instrument(run_span);
+    let (run_state, is_successful) = tokio::select! {
+        result = execution => result?,
+        () = Interruption::shared().wait() => {
+            // Dropping the run stops its agents, so keep the progress they last saved.
+            let run_state = RunState::load(&RunDirectory::get_state_file_path(&run_directory))
+                .map_err(|_| "Interrupted before any progress was saved.".to_string())?;
+            (run_state, false)
+        },
+    };
+
+    let state_file_path = RunDirectory::get_state_file_path(&run_directory);
+    run_state.save(&state_file_path)?;
+    println!("Run state: {}", state_file_path.display());
+
+    // Keep this run's server log with it, so its report never shows another run's.
+    if let Some(server_log) = BackendCodePersistence::read_server_log_since(run_started_at)
+        && let Err(error) = fs::write(RunDirectory::get_server_log_path(&run_directory), server_log)
+    {
+        tracing::warn!(%error, "unable to save the server log of this run");
+    }
+    match RunReport::write(&run_directory, &run_state.project, &run_event_collector.events()) {
+        Ok(report_path) => println!("Run report: {}", report_path.display()),
+        Err(error) => tracing::warn!(%error, "unable to write the run report"),
+    }
+    if Interruption::shared().is_interrupted() {
+        return Err(format!("Interrupted; continue this run with `resume {}`.", run_directory.display()));
+    }
+    if !is_successful {
+        return Err("Some checks failed; see the run report.".to_string());
+    }
+    Ok(())
+}
+
+// Run the agents a command needs, returning where the run got to and whether it succeeded.
+async fn execute_command(
+    command: CommandLineCommand, 
+    pipeline_configuration_path: &Path, 
+    run_directory: &Path, 
+) -> Result<(RunState, bool), String> {
+    let mut managing_agent = match command {
+        CommandLineCommand::New { prompt } => {
+
+            // Check the pipeline before asking the user anything.
+            let pipeline_configuration = PipelineConfiguration::load(pipeline_configuration_path)?;
+            let user_request = if prompt.is_empty() {
+                CommandLine::get_user_response("What website are we building today?")
+            } else {
+                prompt.join(" ")
+            };
+            ManagingAgent::new(user_request, &pipeline_configuration)
+                .await
+                .map_err(|error| format!("Error creating managing agent: {}", error))?
+        },
+        CommandLineCommand::Resume { state } => {
+            let pipeline_configuration = PipelineConfiguration::load(pipeline_configuration_path)?;
+            let state_file_path = if state.is_dir() {
+                RunDirectory::get_state_file_path(&state)
+            } else {
+                state
+            };
+            ManagingAgent::resume(RunState::load(&state_file_path)?, &pipeline_configuration)
+                .map_err(|error| format!("Error creating managing agent: {}", error))?
+        },
+        CommandLineCommand::Fix { project_dir } => return run_backend_step(&project_dir, BackendStep::Fix).await,
+        CommandLineCommand::Test { project_dir } => return run_backend_step(&project_dir, BackendStep::Test).await,
+        CommandLineCommand::Schema { project_dir } => return run_backend_step(&project_dir, BackendStep::Schema).await,
+        CommandLineCommand::Report { .. } | CommandLineCommand::Transcript { .. } => {
+            unreachable!("Reports and transcripts are read without running agents.")
+        },
+    };
+
+    // Save progress as agents finish, so the run can be resumed if it stops.
+    managing_agent.agent_graph.checkpoint_path = Some(RunDirectory::get_state_file_path(run_directory));
     managing_agent.execute()
         .await;
+    let is_successful = managing_agent.agent_graph.is_successful();
+    Ok((managing_agent.run_state(), is_successful))
+}
+
+// Run one step of the backend agent on an existing backend project, instead of the whole pipeline.
+async fn run_backend_step(project_directory: &Path, backend_step: BackendStep) -> Result<(RunState, bool), String> {
+    if !BackendCodePersistence::is_backend_code_project(project_directory) {
+        return Err(format!(
+            "{} is not a backend project; it needs a Cargo.toml and a src/main.rs.", 
+            project_directory.display(), 
+        ));
+    }
+    let project_directory = fs::canonicalize(project_directory)
+        .map_err(|error| format!("Unable to open {}: {}", project_directory.display(), error))?;
+    BackendCodePersistence::use_backend_code_project_path(project_directory.clone());
+    let mut project = Project::new(format!("The backend in {}", project_directory.display()));
+
+    let mut backend_agent = BackendDeveloperAgent::new();
+    let position = backend_agent.attributes.position.clone();
+    EventBus::publish(AgentEvent::AgentStarted {
+        position: position.clone(),
+    });
+    let agent_result = match backend_step {
+        BackendStep::Fix => backend_agent.fix_build(&mut project).await,
+        BackendStep::Test => backend_agent.test_endpoints(&mut project).await,
+        BackendStep::Schema => backend_agent.write_api_endpoint_schema(&mut project).await,
+    }.map_err(|error| error.to_string());
+    EventBus::publish(AgentEvent::AgentFinished {
+        position,
+        report: agent_result.as_ref().ok().cloned(),
+        error: agent_result.as_ref().err().cloned(),
+    });
+    let report = agent_result?;
+
+    if let BackendStep::Schema = backend_step {
+        let api_endpoints_string = serde_json::to_string_pretty(&project.api_endpoint_schema)
+            .expect("Failed to encode API endpoints.");
+        println!("{}", api_endpoints_string);
+    }
+    let is_successful = report.failed_checks.is_empty();
+    let run_state = RunState {
+        project,
+        completed_agents: vec![],
+    };
+    Ok((run_state, is_successful))
 }
@@ -7,6 +7,17 @@
  *
  */
 
+pub mod backend_task;
+pub mod defect;
 pub mod endpoint_route;
+pub mod field_type;
+pub mod pipeline_configuration;
+pub mod project_field;
 pub mod project_scope;
-pub mod project;
\ No newline at end of file
+pub mod project;
+pub mod requirements;
+pub mod review_issue;
+pub mod route_path;
+pub mod run_state;
+pub mod server_address;
+pub mod test_scenario;
\ No newline at end of file
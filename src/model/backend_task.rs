@@ -0,0 +1,77 @@
+/*
+ *  model/backend_task.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use serde::{ Deserialize, Serialize };
+use std::fmt::Debug;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BackendTaskKind {
+    Entity,
+    Endpoint,
+    Integration,
+    Auth,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum BackendTaskStatus {
+    #[default]
+    Pending,
+    InProgress,
+    Done,
+}
+
+// A concrete piece of backend work from the plan, checked off once the code implementing it passes its tests.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BackendTask {
+    pub title: String,
+    pub kind: BackendTaskKind,
+    pub description: String,
+    #[serde(default)]
+    pub status: BackendTaskStatus,
+}
+
+impl BackendTask {
+    // Describe the task for the developer agent.
+    pub fn summary(&self) -> String {
+        format!("[{:?}] {}: {}", self.kind, self.title, self.description)
+    }
+}
+
+// Count the tasks that are done, out of all planned tasks.
+pub fn backend_task_progress(backend_tasks: &[BackendTask]) -> (usize, usize) {
+    let done_count = backend_tasks
+        .iter()
+        .filter(|backend_task| backend_task.status == BackendTaskStatus::Done)
+        .count();
+    (done_count, backend_tasks.len())
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_backend_tasks() {
+        let mut backend_tasks: Vec<BackendTask> = serde_json::from_str(r#"[
+            { "title": "Task entity", "kind": "entity", "description": "A task has an id, a name and a completed flag." },
+            { "title": "Create tasks", "kind": "endpoint", "description": "POST /task stores a new task." }
+        ]"#).expect("Failed to decode backend tasks.");
+
+        assert_eq!(backend_tasks[0].status, BackendTaskStatus::Pending);
+        assert_eq!(backend_tasks[1].summary(), "[Endpoint] Create tasks: POST /task stores a new task.");
+
+        backend_tasks[0].status = BackendTaskStatus::Done;
+        assert_eq!(backend_task_progress(&backend_tasks), (1, 2));
+        assert_eq!(serde_json::to_value(BackendTaskStatus::InProgress).expect("Failed to encode status."), "in_progress");
+    }
+}
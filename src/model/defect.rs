@@ -0,0 +1,95 @@
+/*
+ *  model/defect.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::endpoint_route::HTTPMethod;
+
+use serde::{ Deserialize, Serialize };
+use serde_json::Value;
+use std::fmt::Debug;
+
+// A failed scenario step filed by the QA agent for the backend agent to fix, or a server that failed
+// to start, which has no step or request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Defect {
+    pub scenario: String,
+    #[serde(default)]
+    pub step: Option<usize>,
+    #[serde(default)]
+    pub method: Option<HTTPMethod>,
+    pub url: String,
+    pub request_body: Option<Value>,
+    pub expected_status_codes: Vec<u16>,
+    pub actual_status_code: Option<u16>,
+    pub response_body: Option<String>,
+    pub description: String,
+}
+
+impl Defect {
+    // A server which did not start at `url`, before any scenario could run.
+    pub fn server_startup(url: String, error: &str) -> Self {
+        Self {
+            scenario: "Server startup".to_string(),
+            step: None,
+            method: None,
+            url,
+            request_body: None,
+            expected_status_codes: vec![],
+            actual_status_code: None,
+            response_body: None,
+            description: format!("server failed to start: {}", error),
+        }
+    }
+
+    // Describe the defect in a form the developer agent can act on.
+    pub fn summary(&self) -> String {
+        let mut summary = match (self.step, &self.method) {
+            (Some(step), Some(method)) => format!(
+                "Scenario \"{}\", step {}: {:?} {} {}.", 
+                self.scenario, step + 1, method, self.url, self.description, 
+            ),
+            _ => format!("Scenario \"{}\": {} {}.", self.scenario, self.url, self.description),
+        };
+        if let Some(request_body) = &self.request_body {
+            summary.push_str(&format!("\n  Request body: {}", request_body));
+        }
+        if let Some(response_body) = &self.response_body {
+            summary.push_str(&format!("\n  Response body: {}", response_body));
+        }
+        summary
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defect_summary() {
+        let defect = Defect {
+            scenario: "Create task".to_string(),
+            step: Some(1),
+            method: Some(HTTPMethod::Post),
+            url: "http://127.0.0.1:8080/task".to_string(),
+            request_body: None,
+            expected_status_codes: vec![200],
+            actual_status_code: Some(500),
+            response_body: Some("{}".to_string()),
+            description: "responded with status code 500".to_string(),
+        };
+        assert_eq!(
+            defect.summary(),
+            "Scenario \"Create task\", step 2: Post http://127.0.0.1:8080/task responded with status code 500.\n  Response body: {}",
+        );
+
+        let defect = Defect::server_startup("http://127.0.0.1:8080".to_string(), "connection refused");
+        assert_eq!(defect.summary(), "Scenario \"Server startup\": http://127.0.0.1:8080 server failed to start: connection refused.");
+    }
+}
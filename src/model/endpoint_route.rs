@@ -7,7 +7,13 @@
  *
  */
 
+use super::field_type::{ deserialize_optional_field_type, FieldType };
+use super::route_path::RoutePath;
+
+use crate::utilities::code_naming::{ snake_case, upper_camel_case };
+
 use serde::{ Deserialize, Serialize };
+use std::collections::{ BTreeMap, HashMap, HashSet };
 use std::fmt::Debug;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -18,13 +24,227 @@ pub enum HTTPMethod {
     Post,
     Put,
     Delete,
+    Head,
+    Options,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(from = "EndpointRouteRepresentation")]
 pub struct EndpointRoute {
     pub is_route_dynamic: bool,
     pub method: HTTPMethod,
-    pub request_body: serde_json::Value,
-    pub response: serde_json::Value,
+    pub request_body: Option<FieldType>,
+    pub response: Option<FieldType>,
     pub route: String,
-}
\ No newline at end of file
+    pub path_parameters: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub path_parameter_types: BTreeMap<String, FieldType>,
+    pub status_codes: Vec<u16>,
+}
+
+// Serialized form of an endpoint route, where the derived fields may be missing.
+#[derive(Deserialize)]
+struct EndpointRouteRepresentation {
+    method: HTTPMethod,
+    route: String,
+    #[serde(default, deserialize_with = "deserialize_optional_field_type")]
+    request_body: Option<FieldType>,
+    #[serde(default, deserialize_with = "deserialize_optional_field_type")]
+    response: Option<FieldType>,
+    #[serde(default)]
+    path_parameter_types: BTreeMap<String, FieldType>,
+    #[serde(default)]
+    status_codes: Vec<u16>,
+}
+
+impl From<EndpointRouteRepresentation> for EndpointRoute {
+    fn from(representation: EndpointRouteRepresentation) -> Self {
+        let mut endpoint_route = Self::new(
+            representation.method,
+            representation.route,
+            representation.request_body,
+            representation.response,
+        );
+        endpoint_route.path_parameter_types = representation.path_parameter_types;
+        endpoint_route.status_codes = representation.status_codes;
+        endpoint_route
+    }
+}
+
+impl EndpointRoute {
+    pub fn new(
+        method: HTTPMethod,
+        route: String,
+        request_body: Option<FieldType>,
+        response: Option<FieldType>,
+    ) -> Self {
+        let route_path = RoutePath::parse(&route);
+        Self {
+            is_route_dynamic: route_path.is_dynamic(),
+            method,
+            request_body,
+            response,
+            route,
+            path_parameters: route_path.parameter_names(),
+            path_parameter_types: BTreeMap::new(),
+            status_codes: vec![],
+        }
+    }
+
+    // Whether a response status is a success for this endpoint, any 2xx when none were declared.
+    pub fn accepts_status_code(&self, status_code: u16) -> bool {
+        if self.status_codes.is_empty() {
+            (200..300).contains(&status_code)
+        } else {
+            self.status_codes.contains(&status_code)
+        }
+    }
+
+    pub fn route_path(&self) -> RoutePath {
+        RoutePath::parse(&self.route)
+    }
+
+    // Build an UpperCamelCase name such as `GetTaskId` for the endpoint, used by generated code.
+    pub fn operation_name(&self) -> String {
+        format!("{:?}{}", self.method, upper_camel_case(&self.route_path().template()))
+    }
+
+    // Build the operation names of endpoints, numbering the ones that would otherwise share a name
+    // in generated code, such as `GetTaskId` for both `/task-id` and `/task/{id}`.
+    pub fn operation_names(endpoints: &[EndpointRoute]) -> Vec<String> {
+        let mut used_names: HashSet<String> = HashSet::new();
+        endpoints
+            .iter()
+            .map(|endpoint| {
+                let operation_name = endpoint.operation_name();
+                let mut unique_operation_name = operation_name.clone();
+                let mut number = 1;
+                while !used_names.insert(snake_case(&unique_operation_name)) {
+                    number += 1;
+                    unique_operation_name = format!("{}{}", operation_name, number);
+                }
+                unique_operation_name
+            })
+            .collect()
+    }
+
+    // Find endpoints registered more than once for the same method and path.
+    pub fn find_route_conflicts(endpoints: &[EndpointRoute]) -> Vec<String> {
+        let mut registered_routes: HashMap<(String, String), &String> = HashMap::new();
+        let mut conflicts: Vec<String> = vec![];
+        for endpoint in endpoints {
+            let key = (format!("{:?}", endpoint.method), endpoint.route_path().normalized());
+            match registered_routes.get(&key) {
+                Some(&first_route) => conflicts.push(format!(
+                    "Route conflict: {} {} is registered more than once (first as {}); only the first handler is reachable.",
+                    key.0.to_uppercase(), endpoint.route, first_route,
+                )),
+                None => {
+                    registered_routes.insert(key, &endpoint.route);
+                },
+            }
+        }
+        conflicts
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_legacy_endpoint_schema() {
+        let legacy_schema = r#"[
+            {
+                "route": "/item/{id}",
+                "is_route_dynamic": true,
+                "method": "GET",
+                "request_body": "None",
+                "response": { "id": "number", "name": "string", "tags": ["string"] }
+            },
+            {
+                "route": "/crypto",
+                "is_route_dynamic": false,
+                "method": "POST",
+                "request_body": { "symbol": "string" },
+                "response": "not_provided"
+            }
+        ]"#;
+        let endpoints: Vec<EndpointRoute> = serde_json::from_str(legacy_schema)
+            .expect("Failed to decode legacy endpoint schema.");
+
+        assert_eq!(endpoints[0].path_parameters, vec!["id".to_string()]);
+        assert!(endpoints[0].is_route_dynamic);
+        assert_eq!(endpoints[0].request_body, None);
+        assert_eq!(endpoints[0].status_codes, Vec::<u16>::new());
+        assert!(endpoints[0].accepts_status_code(201));
+        assert!(!endpoints[0].accepts_status_code(404));
+        assert_eq!(endpoints[0].response, Some(FieldType::Object(BTreeMap::from([
+            ("id".to_string(), FieldType::Number),
+            ("name".to_string(), FieldType::String),
+            ("tags".to_string(), FieldType::Array(Box::new(FieldType::String))),
+        ]))));
+        assert_eq!(endpoints[1].response, None);
+        assert!(!endpoints[1].is_route_dynamic);
+    }
+
+    #[test]
+    fn test_endpoint_schema_round_trip() {
+        let mut endpoint = EndpointRoute::new(
+            HTTPMethod::Put,
+            "/user/{user_id}/note/{note_id}".to_string(),
+            Some(FieldType::Object(BTreeMap::from([
+                ("type".to_string(), FieldType::String),
+                ("due".to_string(), FieldType::Nullable(Box::new(FieldType::Number))),
+            ]))),
+            None,
+        );
+        endpoint.path_parameter_types = BTreeMap::from([("note_id".to_string(), FieldType::Integer)]);
+        let encoded = serde_json::to_string(&endpoint)
+            .expect("Failed to encode endpoint.");
+        let decoded: EndpointRoute = serde_json::from_str(&encoded)
+            .expect("Failed to decode endpoint.");
+        assert_eq!(decoded, endpoint);
+        assert_eq!(decoded.path_parameters, vec!["user_id".to_string(), "note_id".to_string()]);
+
+        let truncated_schema = r#"{ "method": "GET", "route": "/task", "response": { "$type": "array" } }"#;
+        let error = serde_json::from_str::<EndpointRoute>(truncated_schema)
+            .expect_err("Decoded an array type without its items.");
+        assert!(error.to_string().contains("missing its `items`"));
+    }
+
+    #[test]
+    fn test_find_route_conflicts() {
+        let endpoints = vec![
+            EndpointRoute::new(HTTPMethod::Get, "/task/{id}".to_string(), None, None),
+            EndpointRoute::new(HTTPMethod::Delete, "/task/{id}".to_string(), None, None),
+            EndpointRoute::new(HTTPMethod::Head, "/task/{id}".to_string(), None, None),
+            EndpointRoute::new(HTTPMethod::Get, "/task/{task_id}".to_string(), None, None),
+        ];
+        let conflicts = EndpointRoute::find_route_conflicts(&endpoints);
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].contains("GET /task/{task_id}"));
+    }
+
+    #[test]
+    fn test_operation_name() {
+        let endpoint = EndpointRoute::new(HTTPMethod::Get, "/user/{user_id}/created-at".to_string(), None, None);
+        assert_eq!(endpoint.operation_name(), "GetUserUserIdCreatedAt");
+    }
+
+    #[test]
+    fn test_operation_names() {
+        let endpoints = [
+            EndpointRoute::new(HTTPMethod::Get, "/task-id".to_string(), None, None),
+            EndpointRoute::new(HTTPMethod::Get, "/task/{id}".to_string(), None, None),
+            EndpointRoute::new(HTTPMethod::Get, "/taskID".to_string(), None, None),
+            EndpointRoute::new(HTTPMethod::Post, "/task".to_string(), None, None),
+        ];
+        assert_eq!(
+            EndpointRoute::operation_names(&endpoints),
+            vec!["GetTaskId", "GetTaskId2", "GetTaskID3", "PostTask"],
+        );
+    }
+}
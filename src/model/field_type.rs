@@ -0,0 +1,124 @@
+/*
+ *  model/field_type.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use serde::{ Deserialize, Deserializer, Serialize, Serializer };
+use serde_json::{ json, Map, Value };
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+// Key marking the tagged form of composite types, which no legacy field shape uses.
+const TYPE_TAG_KEY: &str = "$type";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldType {
+    Integer,
+    Number,
+    String,
+    Bool,
+    Any,
+    Array(Box<FieldType>),
+    Object(BTreeMap<String, FieldType>),
+    Nullable(Box<FieldType>),
+}
+
+impl FieldType {
+    // Convert to the canonical JSON form: plain strings for primitives, tagged objects otherwise.
+    pub fn to_value(&self) -> Value {
+        match self {
+            Self::Integer => Value::String("integer".to_string()),
+            Self::Number => Value::String("number".to_string()),
+            Self::String => Value::String("string".to_string()),
+            Self::Bool => Value::String("bool".to_string()),
+            Self::Any => Value::String("any".to_string()),
+            Self::Array(element_type) => json!({ TYPE_TAG_KEY: "array", "items": element_type.to_value() }),
+            Self::Object(field_types) => {
+                let fields: Map<String, Value> = field_types
+                    .iter()
+                    .map(|(name, field_type)| (name.clone(), field_type.to_value()))
+                    .collect();
+                json!({ TYPE_TAG_KEY: "object", "fields": fields })
+            },
+            Self::Nullable(inner_type) => json!({ TYPE_TAG_KEY: "nullable", "inner": inner_type.to_value() }),
+        }
+    }
+
+    // Parse either the canonical form or the legacy shape format (`{ "id": "number" }`, `["string"]`).
+    pub fn from_value(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::String(type_name) => Ok(match type_name.to_lowercase().as_str() {
+                "integer" => Self::Integer,
+                "number" | "float" => Self::Number,
+                "string" => Self::String,
+                "bool" | "boolean" => Self::Bool,
+                "object" => Self::Object(BTreeMap::new()),
+                _ => Self::Any,
+            }),
+            Value::Array(element_types) => match element_types.first() {
+                Some(element_type) => Ok(Self::Array(Box::new(Self::from_value(element_type)?))),
+                None => Ok(Self::Array(Box::new(Self::Any))),
+            },
+            Value::Object(fields) => match fields.get(TYPE_TAG_KEY).and_then(Value::as_str) {
+                Some("array") => Ok(Self::Array(Box::new(Self::from_value(Self::tagged_member(fields, "array", "items")?)?))),
+                Some("nullable") => Ok(Self::Nullable(Box::new(Self::from_value(Self::tagged_member(fields, "nullable", "inner")?)?))),
+                Some("object") => match Self::tagged_member(fields, "object", "fields")? {
+                    Value::Object(field_types) => Self::object_from_fields(field_types),
+                    _ => Err("Tagged object type has `fields` which are not an object.".to_string()),
+                },
+                Some(tag) => Err(format!("Unknown field type tag `{}`.", tag)),
+                None => Self::object_from_fields(fields),
+            },
+            Value::Null => Ok(Self::Any),
+            other => Err(format!("Unsupported field type description: {}", other)),
+        }
+    }
+
+    fn tagged_member<'a>(fields: &'a Map<String, Value>, tag: &str, key: &str) -> Result<&'a Value, String> {
+        fields
+            .get(key)
+            .ok_or_else(|| format!("Tagged {} type is missing its `{}`.", tag, key))
+    }
+
+    fn object_from_fields(fields: &Map<String, Value>) -> Result<Self, String> {
+        let field_types = fields
+            .iter()
+            .map(|(name, field_type)| Ok((name.clone(), Self::from_value(field_type)?)))
+            .collect::<Result<BTreeMap<String, FieldType>, String>>()?;
+        Ok(Self::Object(field_types))
+    }
+
+    // Parse an optional body, where the legacy format spells absence as "None" or "not_provided".
+    pub fn optional_from_value(value: &Value) -> Result<Option<Self>, String> {
+        match value {
+            Value::Null => Ok(None),
+            Value::String(type_name) if matches!(type_name.to_lowercase().as_str(), "none" | "not_provided" | "") => Ok(None),
+            _ => Self::from_value(value).map(Some),
+        }
+    }
+}
+
+impl Serialize for FieldType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_value().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        Self::from_value(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+// Deserialize an optional field type, accepting the legacy "None" and "not_provided" markers.
+pub fn deserialize_optional_field_type<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<FieldType>, D::Error> {
+    let value = Value::deserialize(deserializer)?;
+    FieldType::optional_from_value(&value).map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,146 @@
+/*
+ *  model/pipeline_configuration.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use serde::{ Deserialize, Serialize };
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+// Which agents run, and with which options; the default pipeline runs when the file does not exist.
+pub const DEFAULT_PIPELINE_CONFIGURATION_PATH: &str = "pipeline.json";
+
+const DEFAULT_MAXIMUM_FEEDBACK_ROUNDS: u8 = 2;
+const DEFAULT_PIPELINE_AGENT_NAMES: &[&str] = &["architect", "planner", "backend", "frontend", "qa"];
+
+// Options that tune one agent in a pipeline; each agent only accepts the options that apply to it.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct AgentOptions {
+    #[serde(default)]
+    pub maximum_bug_count: Option<u8>,
+    #[serde(default)]
+    pub maximum_review_rounds: Option<u8>,
+    #[serde(default)]
+    pub maximum_fix_attempts: Option<u8>,
+    #[serde(default)]
+    pub server_host: Option<String>,
+    #[serde(default)]
+    pub api_base_url: Option<String>,
+}
+
+impl AgentOptions {
+    // Names of the options that are set, as they appear in the configuration file.
+    pub fn set_option_names(&self) -> Vec<&'static str> {
+        [
+            ("maximum_bug_count", self.maximum_bug_count.is_some()),
+            ("maximum_review_rounds", self.maximum_review_rounds.is_some()),
+            ("maximum_fix_attempts", self.maximum_fix_attempts.is_some()),
+            ("server_host", self.server_host.is_some()),
+            ("api_base_url", self.api_base_url.is_some()),
+        ]
+            .into_iter()
+            .filter_map(|(name, is_set)| is_set.then_some(name))
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineStage {
+    pub agent: String,
+    #[serde(default)]
+    pub options: AgentOptions,
+}
+
+// Which agents run, in which order, and with which options.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineConfiguration {
+    pub stages: Vec<PipelineStage>,
+    #[serde(default = "default_maximum_feedback_rounds")]
+    pub maximum_feedback_rounds: u8,
+}
+
+fn default_maximum_feedback_rounds() -> u8 {
+    DEFAULT_MAXIMUM_FEEDBACK_ROUNDS
+}
+
+impl Default for PipelineConfiguration {
+    fn default() -> Self {
+        Self {
+            stages: DEFAULT_PIPELINE_AGENT_NAMES
+                .iter()
+                .map(|agent| PipelineStage {
+                    agent: agent.to_string(),
+                    options: AgentOptions::default(),
+                })
+                .collect(),
+            maximum_feedback_rounds: DEFAULT_MAXIMUM_FEEDBACK_ROUNDS,
+        }
+    }
+}
+
+impl PipelineConfiguration {
+    // Read a pipeline from a JSON file, falling back to the default pipeline when there is no file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str::<Self>(&contents)
+                .map_err(|error| error.to_string())
+                .and_then(|configuration| configuration.validate().map(|()| configuration))
+                .map_err(|error| format!("Invalid pipeline configuration in {}: {}", path.display(), error)),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("Failed to read pipeline configuration {}: {}", path.display(), error)),
+        }
+    }
+
+    // Check the options that decode but cannot work, such as a bug count the backend agent cannot count past.
+    fn validate(&self) -> Result<(), String> {
+        for stage in &self.stages {
+            if stage.options.maximum_bug_count.is_some_and(|maximum_bug_count| maximum_bug_count == u8::MAX) {
+                return Err(format!(
+                    "maximum_bug_count of the {} stage must be less than {}.", 
+                    stage.agent, u8::MAX, 
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pipeline_configuration() {
+        let configuration: PipelineConfiguration = serde_json::from_str(r#"{
+            "stages": [
+                { "agent": "backend", "options": { "maximum_bug_count": 4 } },
+                { "agent": "qa" }
+            ]
+        }"#).expect("Failed to decode pipeline configuration.");
+        assert_eq!(configuration.stages.len(), 2);
+        assert_eq!(configuration.stages[0].options.maximum_bug_count, Some(4));
+        assert_eq!(configuration.stages[1].options, AgentOptions::default());
+        assert_eq!(configuration.maximum_feedback_rounds, DEFAULT_MAXIMUM_FEEDBACK_ROUNDS);
+
+        let misspelled_option = r#"{ "stages": [{ "agent": "backend", "options": { "maximum_bugs": 4 } }] }"#;
+        assert!(serde_json::from_str::<PipelineConfiguration>(misspelled_option).is_err());
+
+        let unbounded_bug_count = r#"{ "stages": [{ "agent": "backend", "options": { "maximum_bug_count": 255 } }] }"#;
+        let configuration: PipelineConfiguration = serde_json::from_str(unbounded_bug_count)
+            .expect("Failed to decode pipeline configuration.");
+        assert!(configuration.validate().is_err());
+
+        let missing_file = Path::new("missing_pipeline_configuration.json");
+        assert_eq!(PipelineConfiguration::load(missing_file), Ok(PipelineConfiguration::default()));
+    }
+}
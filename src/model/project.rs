@@ -7,16 +7,90 @@
  *
  */
 
+use super::backend_task::BackendTask;
+use super::defect::Defect;
 use super::endpoint_route::EndpointRoute;
+use super::project_field::ProjectField;
 use super::project_scope::ProjectScope;
+use super::requirements::Requirements;
+use super::review_issue::ReviewIssue;
+use super::server_address::ServerAddress;
 
 use serde::{ Deserialize, Serialize };
+use serde_json::Value;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Project {
     pub description: String,
+    #[serde(default)]
+    pub requirements: Option<Requirements>,
     pub scope: Option<ProjectScope>,
     pub external_urls: Option<Vec<String>>,
     pub backend_code: Option<String>,
+    #[serde(default)]
+    pub backend_tasks: Vec<BackendTask>,
     pub api_endpoint_schema: Option<Vec<EndpointRoute>>,
+    #[serde(default)]
+    pub server_address: Option<ServerAddress>,
+    #[serde(default)]
+    pub frontend_code: Option<BTreeMap<String, String>>,
+    #[serde(default)]
+    pub review_issues: Vec<ReviewIssue>,
+    #[serde(default)]
+    pub defects: Vec<Defect>,
+}
+
+impl Project {
+    // A project with nothing but its description, for agents to build up.
+    pub fn new(description: String) -> Self {
+        Self {
+            description,
+            requirements: None,
+            scope: None,
+            external_urls: None,
+            backend_code: None,
+            backend_tasks: vec![],
+            api_endpoint_schema: None,
+            server_address: None,
+            frontend_code: None,
+            review_issues: vec![],
+            defects: vec![],
+        }
+    }
+
+    // Encode one field so agents' outputs can be compared before and after they run.
+    pub fn field_value(&self, field: ProjectField) -> Value {
+        let value = match field {
+            ProjectField::Description => serde_json::to_value(&self.description),
+            ProjectField::Requirements => serde_json::to_value(&self.requirements),
+            ProjectField::Scope => serde_json::to_value(self.scope),
+            ProjectField::ExternalUrls => serde_json::to_value(&self.external_urls),
+            ProjectField::BackendCode => serde_json::to_value(&self.backend_code),
+            ProjectField::BackendTasks => serde_json::to_value(&self.backend_tasks),
+            ProjectField::ApiEndpointSchema => serde_json::to_value(&self.api_endpoint_schema),
+            ProjectField::ServerAddress => serde_json::to_value(&self.server_address),
+            ProjectField::FrontendCode => serde_json::to_value(&self.frontend_code),
+            ProjectField::ReviewIssues => serde_json::to_value(&self.review_issues),
+            ProjectField::Defects => serde_json::to_value(&self.defects),
+        };
+        value.expect("Failed to encode project field.")
+    }
+
+    // Take one field from another copy of the project, such as the one an agent worked on.
+    pub fn copy_field(&mut self, field: ProjectField, other: &Project) {
+        match field {
+            ProjectField::Description => self.description = other.description.clone(),
+            ProjectField::Requirements => self.requirements = other.requirements.clone(),
+            ProjectField::Scope => self.scope = other.scope,
+            ProjectField::ExternalUrls => self.external_urls = other.external_urls.clone(),
+            ProjectField::BackendCode => self.backend_code = other.backend_code.clone(),
+            ProjectField::BackendTasks => self.backend_tasks = other.backend_tasks.clone(),
+            ProjectField::ApiEndpointSchema => self.api_endpoint_schema = other.api_endpoint_schema.clone(),
+            ProjectField::ServerAddress => self.server_address = other.server_address.clone(),
+            ProjectField::FrontendCode => self.frontend_code = other.frontend_code.clone(),
+            ProjectField::ReviewIssues => self.review_issues = other.review_issues.clone(),
+            ProjectField::Defects => self.defects = other.defects.clone(),
+        }
+    }
 }
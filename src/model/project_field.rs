@@ -0,0 +1,27 @@
+/*
+ *  model/project_field.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use serde::{ Deserialize, Serialize };
+
+// The parts of a project that agents read and write, used to order agents by their data dependencies.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectField {
+    Description,
+    Requirements,
+    Scope,
+    ExternalUrls,
+    BackendCode,
+    BackendTasks,
+    ApiEndpointSchema,
+    ServerAddress,
+    FrontendCode,
+    ReviewIssues,
+    Defects,
+}
@@ -0,0 +1,66 @@
+/*
+ *  model/requirements.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use serde::{ Deserialize, Serialize };
+use std::fmt::Debug;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Clarification {
+    pub question: String,
+    pub answer: String,
+}
+
+// The user's request together with their answers to the clarifying questions.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Requirements {
+    pub request: String,
+    pub clarifications: Vec<Clarification>,
+}
+
+impl Requirements {
+    // Render the requirements as a document, leaving out questions the user skipped.
+    pub fn to_document(&self) -> String {
+        let mut document = format!("REQUEST: {}", self.request);
+        let answered_clarifications = self.clarifications
+            .iter()
+            .filter(|clarification| !clarification.answer.is_empty());
+        for clarification in answered_clarifications {
+            document.push_str(&format!("\nQ: {}\nA: {}", clarification.question, clarification.answer));
+        }
+        document
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requirements_document() {
+        let requirements = Requirements {
+            request: "a TODO app".to_string(),
+            clarifications: vec![
+                Clarification {
+                    question: "Do users need to log in?".to_string(),
+                    answer: "Yes, with a username and password.".to_string(),
+                },
+                Clarification {
+                    question: "How many users do you expect?".to_string(),
+                    answer: String::new(),
+                },
+            ],
+        };
+        assert_eq!(
+            requirements.to_document(),
+            "REQUEST: a TODO app\nQ: Do users need to log in?\nA: Yes, with a username and password.",
+        );
+    }
+}
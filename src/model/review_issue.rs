@@ -0,0 +1,91 @@
+/*
+ *  model/review_issue.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use serde::{ Deserialize, Serialize };
+use std::fmt::Debug;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReviewCategory {
+    Correctness,
+    Security,
+    Validation,
+    Concurrency,
+    Performance,
+    Maintainability,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, PartialOrd)]
+#[serde(rename_all = "lowercase")]
+pub enum ReviewSeverity {
+    Blocking,
+    Major,
+    Minor,
+}
+
+// An issue raised by the code reviewer, located by the function it concerns.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ReviewIssue {
+    pub category: ReviewCategory,
+    pub severity: ReviewSeverity,
+    pub location: String,
+    pub description: String,
+    pub suggestion: String,
+}
+
+impl ReviewIssue {
+    pub fn is_blocking(&self) -> bool {
+        self.severity == ReviewSeverity::Blocking
+    }
+
+    // Describe the issue in a form the developer agent can act on.
+    pub fn summary(&self) -> String {
+        format!(
+            "[{:?}/{:?}] in `{}`: {} Suggested fix: {}", 
+            self.severity, self.category, self.location, self.description, self.suggestion, 
+        )
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_review_issues() {
+        let review_issues: Vec<ReviewIssue> = serde_json::from_str(r#"[
+            {
+                "category": "security",
+                "severity": "blocking",
+                "location": "login",
+                "description": "Passwords are stored and compared in plaintext.",
+                "suggestion": "Store a salted hash and compare hashes."
+            },
+            {
+                "category": "concurrency",
+                "severity": "minor",
+                "location": "create_task",
+                "description": "The database lock is held while writing the file.",
+                "suggestion": "Clone the data, release the lock, then write."
+            }
+        ]"#).expect("Failed to decode review issues.");
+
+        let blocking_issues: Vec<&ReviewIssue> = review_issues
+            .iter()
+            .filter(|review_issue| review_issue.is_blocking())
+            .collect();
+        assert_eq!(blocking_issues.len(), 1);
+        assert_eq!(
+            blocking_issues[0].summary(),
+            "[Blocking/Security] in `login`: Passwords are stored and compared in plaintext. Suggested fix: Store a salted hash and compare hashes.",
+        );
+    }
+}
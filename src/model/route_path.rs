@@ -0,0 +1,132 @@
+/*
+ *  model/route_path.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use std::fmt::Debug;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RouteSegment {
+    Static(String),
+    Parameter(String),
+    Wildcard(String),
+}
+
+// A route such as `/task/{id}` or `/static/{tail:.*}`, split into its segments.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoutePath {
+    pub segments: Vec<RouteSegment>,
+}
+
+impl RoutePath {
+    // Parse actix-web route syntax, where `{name:.*}` or `{name:.+}` captures the remaining path.
+    pub fn parse(route: &str) -> Self {
+        let segments = route
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                let Some(parameter) = segment.strip_prefix('{').and_then(|segment| segment.strip_suffix('}')) else {
+                    return RouteSegment::Static(segment.to_string());
+                };
+                match parameter.split_once(':') {
+                    Some((name, ".*" | ".+")) => RouteSegment::Wildcard(name.to_string()),
+                    Some((name, _)) => RouteSegment::Parameter(name.to_string()),
+                    None => RouteSegment::Parameter(parameter.to_string()),
+                }
+            })
+            .collect();
+        Self { segments }
+    }
+
+    pub fn is_dynamic(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| !matches!(segment, RouteSegment::Static(_)))
+    }
+
+    pub fn parameter_names(&self) -> Vec<String> {
+        self.segments
+            .iter()
+            .filter_map(|segment| match segment {
+                RouteSegment::Static(_) => None,
+                RouteSegment::Parameter(name) | RouteSegment::Wildcard(name) => Some(name.clone()),
+            })
+            .collect()
+    }
+
+    // Get the leading static part of the route, which names the resource it acts on.
+    pub fn static_prefix(&self) -> String {
+        let static_segments: Vec<&str> = self.segments
+            .iter()
+            .map_while(|segment| match segment {
+                RouteSegment::Static(name) => Some(name.as_str()),
+                _ => None,
+            })
+            .collect();
+        format!("/{}", static_segments.join("/"))
+    }
+
+    // Render the route with plain `{name}` parameters, as used by OpenAPI path templates.
+    pub fn template(&self) -> String {
+        self.render(|segment| match segment {
+            RouteSegment::Static(name) => name.clone(),
+            RouteSegment::Parameter(name) | RouteSegment::Wildcard(name) => format!("{{{}}}", name),
+        })
+    }
+
+    // Render the route with parameter names erased, so `/task/{id}` and `/task/{task_id}` compare equal.
+    pub fn normalized(&self) -> String {
+        self.render(|segment| match segment {
+            RouteSegment::Static(name) => name.clone(),
+            RouteSegment::Parameter(_) => "{}".to_string(),
+            RouteSegment::Wildcard(_) => "{*}".to_string(),
+        })
+    }
+
+    // Render the route with every parameter replaced by a concrete value.
+    pub fn fill(&self, parameter_value: &str, wildcard_value: &str) -> String {
+        self.render(|segment| match segment {
+            RouteSegment::Static(name) => name.clone(),
+            RouteSegment::Parameter(_) => parameter_value.to_string(),
+            RouteSegment::Wildcard(_) => wildcard_value.to_string(),
+        })
+    }
+
+    fn render(&self, render_segment: impl Fn(&RouteSegment) -> String) -> String {
+        let rendered_segments: Vec<String> = self.segments.iter().map(render_segment).collect();
+        format!("/{}", rendered_segments.join("/"))
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_route_path() {
+        let route_path = RoutePath::parse("/user/{user_id}/file/{path:.*}");
+        assert_eq!(route_path.segments, vec![
+            RouteSegment::Static("user".to_string()),
+            RouteSegment::Parameter("user_id".to_string()),
+            RouteSegment::Static("file".to_string()),
+            RouteSegment::Wildcard("path".to_string()),
+        ]);
+        assert!(route_path.is_dynamic());
+        assert_eq!(route_path.parameter_names(), vec!["user_id".to_string(), "path".to_string()]);
+        assert_eq!(route_path.static_prefix(), "/user");
+        assert_eq!(route_path.template(), "/user/{user_id}/file/{path}");
+        assert_eq!(route_path.normalized(), "/user/{}/file/{*}");
+        assert_eq!(route_path.fill("7", "a.txt"), "/user/7/file/a.txt");
+
+        let static_route_path = RoutePath::parse("/");
+        assert!(!static_route_path.is_dynamic());
+        assert_eq!(static_route_path.template(), "/");
+        assert_eq!(RoutePath::parse("/task/{id:\\d+}").normalized(), "/task/{}");
+    }
+}
@@ -0,0 +1,43 @@
+/*
+ *  model/run_state.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::project::Project;
+
+use serde::{ Deserialize, Serialize };
+use std::fs;
+use std::path::Path;
+
+// Where a run got to: the project so far, and the agents that are done with it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RunState {
+    pub project: Project,
+    #[serde(default)]
+    pub completed_agents: Vec<String>,
+}
+
+impl RunState {
+    // Read a state saved by an earlier run.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|error| format!("Failed to read run state {}: {}", path.display(), error))?;
+        serde_json::from_str(&contents)
+            .map_err(|error| format!("Invalid run state in {}: {}", path.display(), error))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)
+                .map_err(|error| format!("Failed to create run directory {}: {}", directory.display(), error))?;
+        }
+        let contents = serde_json::to_string_pretty(self)
+            .expect("Failed to encode run state.");
+        fs::write(path, contents)
+            .map_err(|error| format!("Failed to save run state {}: {}", path.display(), error))
+    }
+}
@@ -0,0 +1,32 @@
+/*
+ *  model/test_scenario.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::endpoint_route::HTTPMethod;
+
+use serde::{ Deserialize, Serialize };
+use serde_json::Value;
+use std::fmt::Debug;
+
+// One request of a scenario, where `{id}` in the path stands for the last identifier the server returned.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TestScenarioStep {
+    pub method: HTTPMethod,
+    pub path: String,
+    #[serde(default)]
+    pub request_body: Option<Value>,
+    pub expected_status_codes: Vec<u16>,
+}
+
+// A black-box user journey written from the project description, independently of the code.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TestScenario {
+    pub name: String,
+    pub description: String,
+    pub steps: Vec<TestScenarioStep>,
+}
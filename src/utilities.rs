@@ -9,5 +9,23 @@
 
 pub mod ai_tasks;
 pub mod backend_code_persistence;
+pub mod backend_server;
+pub mod code_naming;
 pub mod command_line;
-pub mod networking;
\ No newline at end of file
+pub mod command_line_arguments;
+pub mod endpoint_testing;
+pub mod event_bus;
+pub mod frontend_code_persistence;
+pub mod frontend_smoke_check;
+pub mod integration_test_generator;
+pub mod interruption;
+pub mod logging;
+pub mod networking;
+pub mod open_api;
+pub mod rest_api_analyzer;
+pub mod run_directory;
+pub mod run_report;
+pub mod rust_client_generator;
+pub mod scenario_testing;
+pub mod transcripts;
+pub mod typescript_client_generator;
\ No newline at end of file
@@ -7,10 +7,38 @@
  *
  */
 
-use super::command_line::CommandLine;
+use super::event_bus::{ AgentEvent, EventBus };
+use super::transcripts::{ redact_secrets, Transcript };
 use crate::api::open_ai::request::{ call_gpt, Role, Model, Message };
 
 use serde::de::DeserializeOwned;
+use std::cell::Cell;
+use std::future::Future;
+use std::sync::OnceLock;
+use tracing::Span;
+use tracing::field::Empty;
+
+const DEFAULT_MODEL: Model = Model::GPT_5_2;
+
+static SELECTED_MODEL: OnceLock<Model> = OnceLock::new();
+
+tokio::task_local! {
+    // Number of LLM calls made by the agent running in the current task.
+    static AI_TASK_REQUEST_COUNT: Cell<u32>;
+}
+
+// Run an agent's work, counting the LLM calls it makes, including those of agents it runs itself.
+pub async fn count_ai_task_requests<F: Future>(future: F) -> (F::Output, u32) {
+    AI_TASK_REQUEST_COUNT.scope(Cell::new(0), async {
+        let output = future.await;
+        (output, AI_TASK_REQUEST_COUNT.with(|count| count.get()))
+    }).await
+}
+
+// Use another model than the default for every LLM call; only the first call has an effect.
+pub fn select_model(model: Model) {
+    _ = SELECTED_MODEL.set(model);
+}
 
 // Extend AI function to encourage specific output.
 fn extend_ai_function(
@@ -34,9 +62,14 @@ fn extend_ai_function(
 }
 
 // Performs call to LLM.
+#[tracing::instrument(
+    name = "llm_call", 
+    skip_all, 
+    fields(position = agent_position, operation = agent_operation, prompt_tokens = Empty, completion_tokens = Empty), 
+)]
 pub async fn ai_task_request(
     message_context: String,
-    agent_position: &String,
+    agent_position: &str,
     agent_operation: &str,
     ai_function: for<'a> fn(&'a str) -> &'static str,
 ) -> String {
@@ -47,35 +80,65 @@ pub async fn ai_task_request(
         &message_context
     );
 
-    // Print current status.
-    CommandLine::print_agent_generation_message(
-        agent_position, 
-        agent_operation, 
-    );
+    // Announce the call to event subscribers, such as the terminal.
+    EventBus::publish(AgentEvent::LlmCallStarted {
+        position: agent_position.to_string(),
+        operation: agent_operation.to_string(),
+    });
 
-    // Get LLM response.
-    let model = Model::GPT_5_2;
-    let llm_response_result = 
+    // Count the call for the agent's report, when it is being counted.
+    _ = AI_TASK_REQUEST_COUNT.try_with(|count| count.set(count.get() + 1));
+
+    // Get LLM response, trying once more on failure.
+    let model = SELECTED_MODEL.get().cloned().unwrap_or(DEFAULT_MODEL);
+    tracing::debug!(prompt = %redact_secrets(&function_message.content), "calling LLM");
+    let mut llm_response_result = 
         call_gpt(model.clone(), vec![function_message.clone()])
             .await;
+    if let Some(error_message) = llm_response_result.as_ref().err().map(|error| error.to_string()) {
+        tracing::warn!(error = %error_message, "LLM call failed, retrying");
+        llm_response_result = call_gpt(model.clone(), vec![function_message.clone()])
+            .await;
+    }
 
-    match llm_response_result {
+    EventBus::publish(AgentEvent::LlmCallFinished {
+        position: agent_position.to_string(),
+        operation: agent_operation.to_string(),
+        model: Some(model.clone()),
+        succeeded: llm_response_result.is_ok(),
+        usage: llm_response_result.as_ref().ok().and_then(|(_, usage)| usage.clone()),
+    });
+
+    let (llm_response, usage) = match llm_response_result {
         Ok(llm_response_result) => llm_response_result,
-        Err(_) => {
-            call_gpt(model.clone(), vec![function_message.clone()])
-                .await
-                .expect("Failed twice to call GPT.")
+        Err(error) => {
+            tracing::error!(%error, "LLM call failed twice");
+            panic!("Failed twice to call GPT.");
         },
+    };
+    if let Some(usage) = &usage {
+        Span::current().record("prompt_tokens", usage.prompt_tokens);
+        Span::current().record("completion_tokens", usage.completion_tokens);
     }
+    tracing::debug!(response = %redact_secrets(&llm_response), "LLM responded");
+    Transcript::record(
+        agent_position, 
+        agent_operation, 
+        &function_message.content, 
+        &llm_response, 
+        usage.as_ref(), 
+    );
+    llm_response
 }
 
 // Performs call to LLM - Decoded version.
+// A response that does not decode is an error of the agent, rather than a panic, so the agent's changes roll back.
 pub async fn ai_task_request_decoded<T: DeserializeOwned>(
     message_context: String,
-    agent_position: &String,
+    agent_position: &str,
     agent_operation: &str,
     ai_function: for<'a> fn(&'a str) -> &'static str,
-) -> T {
+) -> Result<T, String> {
     let llm_response: String = ai_task_request(
         message_context, 
         agent_position, 
@@ -84,10 +147,12 @@ pub async fn ai_task_request_decoded<T: DeserializeOwned>(
     )
     .await;
 
-    let decoded_response: T = serde_json::from_str(llm_response.as_str())
-        .expect("Failed to decode AI response from serde_json.");
+    decode_ai_response(&llm_response, agent_operation)
+}
 
-    decoded_response
+fn decode_ai_response<T: DeserializeOwned>(llm_response: &str, agent_operation: &str) -> Result<T, String> {
+    serde_json::from_str(llm_response)
+        .map_err(|error| format!("Failed to decode AI response for {}: {}", agent_operation, error))
 }
 
 
@@ -106,6 +171,17 @@ mod tests {
         assert_eq!(extended_message.role, Role::System);
     }
 
+    #[test]
+    fn test_decode_ai_response() {
+        let decoded_response: Vec<String> = decode_ai_response(r#"["a", "b"]"#, "print_site_urls")
+            .expect("Failed to decode a valid response.");
+        assert_eq!(decoded_response, vec!["a".to_string(), "b".to_string()]);
+
+        let error = decode_ai_response::<Vec<String>>("Sure! Here are the URLs:", "print_site_urls")
+            .expect_err("Decoded an invalid response.");
+        assert!(error.starts_with("Failed to decode AI response for print_site_urls:"), "{}", error);
+    }
+
     // Disable this test for global `cargo test` commands, 
     // because it requires OpenAI keys in environment variables.
     //#[tokio::test]
@@ -113,7 +189,7 @@ mod tests {
     async fn test_ai_task_request() {
         let result = ai_task_request(
             "Build me a web site for making stock price API requests.".to_string(), 
-            &"Managing Agent".to_string(), 
+            "Managing Agent", 
             "Defining user requirements", 
             convert_user_input_to_goal,
         )
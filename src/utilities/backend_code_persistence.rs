@@ -7,25 +7,55 @@
  *
  */
 
+use std::env;
 use std::fs;
 use std::path::{ Path, PathBuf };
+use std::sync::OnceLock;
+use std::time::SystemTime;
 
 const BACKEND_CODE_PROJECT_PATH: &str           = "backend_code";
 const CODE_TEMPLATE_RELATIVE_FILE_PATH: &str    = "backend_code/src/template.rs";
-const EXECUTABLE_MAIN_RELATIVE_PATH: &str       = "backend_code/src/main.rs";
-const API_SCHEMA_RELATIVE_PATH: &str            = "backend_code/schemas/api_schema.json";
+const EXECUTABLE_MAIN_RELATIVE_PATH: &str       = "src/main.rs";
+const API_SCHEMA_RELATIVE_PATH: &str            = "schemas/api_schema.json";
+const OPEN_API_RELATIVE_PATH: &str             = "schemas/openapi.json";
+const SERVER_LOG_RELATIVE_PATH: &str            = "logs/backend_server.log";
+const TYPESCRIPT_CLIENT_RELATIVE_PATH: &str     = "clients/api_client.ts";
+const INTEGRATION_TESTS_RELATIVE_PATH: &str     = "tests/api_endpoints.rs";
+const BACKEND_CODE_BINARY_NAME: &str            = "backend_code";
+const BACKEND_CLIENT_PROJECT_PATH: &str         = "backend_client";
+
+// Backend project worked on instead of `backend_code`, such as one given on the command line.
+static BACKEND_CODE_PROJECT_PATH_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
 
 pub struct BackendCodePersistence {}
 
 impl BackendCodePersistence {
+    // Work on an existing backend project instead of `backend_code`; only the first call has an effect.
+    pub fn use_backend_code_project_path(backend_code_project_path: PathBuf) {
+        _ = BACKEND_CODE_PROJECT_PATH_OVERRIDE.set(backend_code_project_path);
+    }
+
+    // Determine whether a directory holds a backend project to build, such as one generated earlier.
+    pub fn is_backend_code_project(path: &Path) -> bool {
+        path.join("Cargo.toml").is_file() && path.join(EXECUTABLE_MAIN_RELATIVE_PATH).is_file()
+    }
+
     // Get backend code project path.
     pub fn get_backend_code_project_path() -> PathBuf {
+        if let Some(backend_code_project_path) = BACKEND_CODE_PROJECT_PATH_OVERRIDE.get() {
+            return backend_code_project_path.clone();
+        }
         let project_directory = env!("CARGO_MANIFEST_DIR");
         let backend_code_project_path_string = format!("{}/{}", project_directory, BACKEND_CODE_PROJECT_PATH);
         let backend_code_project_path = Path::new(&backend_code_project_path_string);
         backend_code_project_path.to_path_buf()
     }
 
+    // Get generated client crate path, next to the backend project being worked on.
+    pub fn get_backend_client_project_path() -> PathBuf {
+        Self::get_backend_code_project_path().with_file_name(BACKEND_CLIENT_PROJECT_PATH)
+    }
+
     // Get code template file path.
     fn get_code_template_file_path() -> PathBuf {
         let project_directory = env!("CARGO_MANIFEST_DIR");
@@ -36,34 +66,77 @@ impl BackendCodePersistence {
 
     // Get executable main file path.
     fn get_executable_main_file_path() -> PathBuf {
-        let project_directory = env!("CARGO_MANIFEST_DIR");
-        let file_path_string = format!("{}/{}", project_directory, EXECUTABLE_MAIN_RELATIVE_PATH);
-        let file_path = Path::new(&file_path_string);
-        file_path.to_path_buf()
+        Self::get_backend_code_project_path().join(EXECUTABLE_MAIN_RELATIVE_PATH)
     }
 
     // Get API schemas file path.
     fn get_api_schemas_file_path() -> PathBuf {
-        let project_directory = env!("CARGO_MANIFEST_DIR");
-        let file_path_string = format!("{}/{}", project_directory, API_SCHEMA_RELATIVE_PATH);
-        let file_path = Path::new(&file_path_string);
-        file_path.to_path_buf()
+        Self::get_backend_code_project_path().join(API_SCHEMA_RELATIVE_PATH)
+    }
+
+    // Get OpenAPI document file path.
+    fn get_open_api_file_path() -> PathBuf {
+        Self::get_backend_code_project_path().join(OPEN_API_RELATIVE_PATH)
+    }
+
+    // Get TypeScript client file path.
+    fn get_typescript_client_file_path() -> PathBuf {
+        Self::get_backend_code_project_path().join(TYPESCRIPT_CLIENT_RELATIVE_PATH)
+    }
+
+    // Get integration test suite file path.
+    fn get_integration_tests_file_path() -> PathBuf {
+        Self::get_backend_code_project_path().join(INTEGRATION_TESTS_RELATIVE_PATH)
+    }
+
+    // Get server log file path.
+    fn get_server_log_file_path() -> PathBuf {
+        Self::get_backend_code_project_path().join(SERVER_LOG_RELATIVE_PATH)
+    }
+
+    // Get the path of the binary produced by `cargo build` for the backend code, named after its package.
+    pub fn get_backend_code_binary_path() -> PathBuf {
+        let backend_code_project_path = Self::get_backend_code_project_path();
+        let target_directory = match env::var("CARGO_TARGET_DIR") {
+            Ok(target_directory) => PathBuf::from(target_directory),
+            Err(_) => backend_code_project_path.join("target"),
+        };
+        let binary_name = format!("{}{}", Self::read_backend_code_package_name(), env::consts::EXE_SUFFIX);
+        target_directory.join("debug").join(binary_name)
+    }
+
+    // Read the package name of the backend code, which also names its binary.
+    pub fn read_backend_code_package_name() -> String {
+        fs::read_to_string(Self::get_backend_code_project_path().join("Cargo.toml"))
+            .ok()
+            .and_then(|manifest| read_package_name(&manifest))
+            .unwrap_or_else(|| BACKEND_CODE_BINARY_NAME.to_string())
     }
 
     // Get code template.
     pub fn read_code_template_contents() -> String {
         let code_template_file_path = Self::get_code_template_file_path();
-        let code_template = fs::read_to_string(code_template_file_path)
-            .expect("Failed to read code template.");
-        code_template
+        fs::read_to_string(code_template_file_path)
+            .expect("Failed to read code template.")
     }
 
     // Get executable main contents.
     pub fn read_executable_main_contents() -> String {
         let executable_main_file_path = Self::get_executable_main_file_path();
-        let executable_main_contents = fs::read_to_string(executable_main_file_path)
-            .expect("Failed to read executable main contents.");
-        executable_main_contents
+        fs::read_to_string(executable_main_file_path)
+            .expect("Failed to read executable main contents.")
+    }
+
+    // Read the output of the last server run, when it ran after the given time.
+    pub fn read_server_log_since(time: SystemTime) -> Option<String> {
+        let server_log_file_path = Self::get_server_log_file_path();
+        let modified_time = fs::metadata(&server_log_file_path)
+            .and_then(|metadata| metadata.modified())
+            .ok()?;
+        if modified_time < time {
+            return None;
+        }
+        fs::read_to_string(server_log_file_path).ok()
     }
 
     // Save new backend code.
@@ -76,9 +149,92 @@ impl BackendCodePersistence {
     // Save JSON API endpoint schema.
     pub fn save_api_endpoints(api_endpoints: &String) {
         let api_schemas_file_path = Self::get_api_schemas_file_path();
+        if let Some(api_schemas_directory) = api_schemas_file_path.parent() {
+            fs::create_dir_all(api_schemas_directory)
+                .expect("Failed to create API schemas directory.");
+        }
         fs::write(api_schemas_file_path, api_endpoints)
             .expect("Failed to write API endpoints to file.");
     }
+
+    // Save OpenAPI document.
+    pub fn save_open_api_document(open_api_document: &String) {
+        let open_api_file_path = Self::get_open_api_file_path();
+        if let Some(open_api_directory) = open_api_file_path.parent() {
+            fs::create_dir_all(open_api_directory)
+                .expect("Failed to create OpenAPI document directory.");
+        }
+        fs::write(open_api_file_path, open_api_document)
+            .expect("Failed to write OpenAPI document to file.");
+    }
+
+    // Save generated integration test suite.
+    pub fn save_integration_tests(contents: &String) {
+        let integration_tests_file_path = Self::get_integration_tests_file_path();
+        if let Some(integration_tests_directory) = integration_tests_file_path.parent() {
+            fs::create_dir_all(integration_tests_directory)
+                .expect("Failed to create integration tests directory.");
+        }
+        fs::write(integration_tests_file_path, contents)
+            .expect("Failed to write integration tests file.");
+    }
+
+    // Save generated TypeScript client module.
+    pub fn save_typescript_client(contents: &String) {
+        let typescript_client_file_path = Self::get_typescript_client_file_path();
+        if let Some(typescript_client_directory) = typescript_client_file_path.parent() {
+            fs::create_dir_all(typescript_client_directory)
+                .expect("Failed to create TypeScript client directory.");
+        }
+        fs::write(typescript_client_file_path, contents)
+            .expect("Failed to write TypeScript client file.");
+    }
+
+    // Save generated client crate manifest and library.
+    pub fn save_backend_client(manifest: &String, library: &String) {
+        let backend_client_project_path = Self::get_backend_client_project_path();
+        let source_directory = backend_client_project_path.join("src");
+        fs::create_dir_all(&source_directory)
+            .expect("Failed to create backend client directory.");
+        fs::write(backend_client_project_path.join("Cargo.toml"), manifest)
+            .expect("Failed to write backend client Cargo.toml file.");
+        fs::write(source_directory.join("lib.rs"), library)
+            .expect("Failed to write backend client lib.rs file.");
+    }
+
+    // Save captured backend server output.
+    pub fn save_server_log(contents: &String) {
+        let server_log_file_path = Self::get_server_log_file_path();
+        if let Some(server_log_directory) = server_log_file_path.parent() {
+            fs::create_dir_all(server_log_directory)
+                .expect("Failed to create server log directory.");
+        }
+        fs::write(server_log_file_path, contents)
+            .expect("Failed to write server log file.");
+    }
+}
+
+
+
+// Read the `name` of the `[package]` section of a Cargo manifest.
+fn read_package_name(manifest: &str) -> Option<String> {
+    let mut is_package_section = false;
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            is_package_section = line == "[package]";
+            continue;
+        }
+        if !is_package_section {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        if key.trim() == "name" {
+            return Some(value.trim().trim_matches('"').to_string());
+        }
+    }
+    None
 }
 
 
@@ -87,6 +243,19 @@ impl BackendCodePersistence {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_read_package_name() {
+        let manifest = "[package]\nedition = \"2024\"\nname = \"todo-api\"\n\n[dependencies]\nname = \"other\"\n";
+        assert_eq!(read_package_name(manifest).as_deref(), Some("todo-api"));
+        assert_eq!(read_package_name("[workspace]\nname = \"other\"\n"), None);
+
+        let backend_code_binary_path = BackendCodePersistence::get_backend_code_binary_path();
+        assert_eq!(
+            backend_code_binary_path.file_stem().and_then(|file_stem| file_stem.to_str()), 
+            Some(BACKEND_CODE_BINARY_NAME), 
+        );
+    }
+
     #[test]
     fn test_backend_code_paths() {
         let backend_code_project_path = BackendCodePersistence::get_backend_code_project_path();
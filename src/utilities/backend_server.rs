@@ -0,0 +1,312 @@
+/*
+ *  utilities/backend_server.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::backend_code_persistence::BackendCodePersistence;
+use super::interruption::{ INTERRUPTED_EXIT_CODE, Interruption };
+use super::networking::{ BACKEND_HOST_ENVIRONMENT_VARIABLE, BACKEND_PORT_ENVIRONMENT_VARIABLE };
+
+use std::fmt;
+use std::io::{ BufRead, BufReader, Read };
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::path::{ Path, PathBuf };
+use std::process::{ Child, Command, ExitStatus, Stdio };
+use std::sync::{ Arc, Mutex };
+use std::thread;
+use std::time::{ Duration, Instant };
+use tokio::net::TcpStream;
+use tokio::time;
+
+const READINESS_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+// Process IDs of every server still running, so an interrupt can tear them down.
+static RUNNING_SERVER_PROCESS_IDS: Mutex<Vec<u32>> = Mutex::new(vec![]);
+
+#[derive(Debug)]
+pub enum BackendServerStartupError {
+    Exited { status: ExitStatus, output: String },
+    TimedOut { deadline: Duration, output: String },
+}
+
+impl fmt::Display for BackendServerStartupError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Exited { status, output } => write!(
+                formatter,
+                "Backend server exited during startup with {}.\nSERVER_OUTPUT:\n{}",
+                status, output,
+            ),
+            Self::TimedOut { deadline, output } => write!(
+                formatter,
+                "Backend server did not accept connections within {} seconds.\nSERVER_OUTPUT:\n{}",
+                deadline.as_secs(), output,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BackendServerStartupError {}
+
+// Guard over a running backend server process, torn down when dropped.
+#[derive(Debug)]
+pub struct BackendServer {
+    child: Child,
+    output: Arc<Mutex<String>>,
+    is_stopped: bool,
+    // Once the child is reaped its process ID may be reused, so its group must not be killed anymore.
+    is_reaped: bool,
+    // Scratch directory the server runs in, removed once it stops.
+    data_directory: Option<PathBuf>,
+}
+
+impl BackendServer {
+    // Spawn the server process in its own process group, capturing its stdout and stderr.
+    pub fn launch(mut command: Command, working_directory: &Path) -> std::io::Result<Self> {
+        #[cfg(unix)]
+        command.process_group(0);
+
+        let mut child = command
+            .current_dir(working_directory)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        Self::register_process_id(child.id());
+
+        // Drain both pipes so a chatty server never blocks on a full buffer.
+        let output = Arc::new(Mutex::new(String::new()));
+        if let Some(stdout) = child.stdout.take() {
+            Self::capture_output(stdout, Arc::clone(&output));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            Self::capture_output(stderr, Arc::clone(&output));
+        }
+
+        Ok(Self { child, output, is_stopped: false, is_reaped: false, data_directory: None })
+    }
+
+    // Run the built backend binary directly, so no `cargo` process sits in between, bound to the given address.
+    // It runs in a fresh data directory per test run, so files like `database.json` never leak between runs.
+    pub fn launch_backend_binary(test_run_name: &str, host: &str, port: u16) -> std::io::Result<Self> {
+        let data_directory = std::env::temp_dir().join(format!("backend_code_{}_{}", test_run_name, std::process::id()));
+        _ = std::fs::remove_dir_all(&data_directory);
+        std::fs::create_dir_all(&data_directory)?;
+
+        // The binary path may be relative, and must not be resolved from the data directory.
+        let mut command = Command::new(std::path::absolute(BackendCodePersistence::get_backend_code_binary_path())?);
+        command
+            .env(BACKEND_HOST_ENVIRONMENT_VARIABLE, host)
+            .env(BACKEND_PORT_ENVIRONMENT_VARIABLE, port.to_string());
+        let mut backend_server = Self::launch(command, &data_directory)?;
+        backend_server.data_directory = Some(data_directory);
+        Ok(backend_server)
+    }
+
+    // Kill every running server when the user interrupts the program with Ctrl-C, and interrupt the run so it can save its state.
+    // Pressing Ctrl-C again exits right away, such as when the run is waiting for the user to answer.
+    pub fn install_interrupt_handler() {
+        tokio::spawn(async {
+            while tokio::signal::ctrl_c().await.is_ok() {
+                let process_ids = RUNNING_SERVER_PROCESS_IDS
+                    .lock()
+                    .map(|process_ids| process_ids.clone())
+                    .unwrap_or_default();
+                for process_id in process_ids {
+                    Self::terminate_process_group(process_id);
+                }
+                if Interruption::shared().is_interrupted() {
+                    std::process::exit(i32::from(INTERRUPTED_EXIT_CODE));
+                }
+                Interruption::shared().interrupt();
+            }
+        });
+    }
+
+    fn register_process_id(process_id: u32) {
+        if let Ok(mut process_ids) = RUNNING_SERVER_PROCESS_IDS.lock() {
+            process_ids.push(process_id);
+        }
+    }
+
+    fn unregister_process_id(process_id: u32) {
+        if let Ok(mut process_ids) = RUNNING_SERVER_PROCESS_IDS.lock() {
+            process_ids.retain(|&running_process_id| running_process_id != process_id);
+        }
+    }
+
+    // Kill the whole process group, including anything the server spawned.
+    #[cfg(unix)]
+    fn terminate_process_group(process_id: u32) {
+        // SAFETY: `kill` has no memory safety requirements; a negative PID targets the group.
+        unsafe {
+            libc::kill(-(process_id as libc::pid_t), libc::SIGKILL);
+        }
+    }
+
+    #[cfg(not(unix))]
+    fn terminate_process_group(_process_id: u32) {}
+
+    fn capture_output<R: Read + Send + 'static>(stream: R, output: Arc<Mutex<String>>) {
+        thread::spawn(move || {
+            for line in BufReader::new(stream).lines().map_while(Result::ok) {
+                if let Ok(mut output) = output.lock() {
+                    output.push_str(&line);
+                    output.push('\n');
+                }
+            }
+        });
+    }
+
+    // Get everything the server has printed so far.
+    pub fn captured_output(&self) -> String {
+        self.output
+            .lock()
+            .map(|output| output.clone())
+            .unwrap_or_default()
+    }
+
+    // Poll the port until the server accepts connections, exits, or the deadline passes.
+    pub async fn wait_until_ready(
+        &mut self,
+        host: &str,
+        port: u16,
+        deadline: Duration,
+    ) -> Result<(), BackendServerStartupError> {
+        let start = Instant::now();
+        loop {
+            if let Ok(Some(status)) = self.child.try_wait() {
+                self.mark_reaped();
+                // Give the reader threads a moment to flush the final lines.
+                time::sleep(READINESS_POLL_INTERVAL).await;
+                return Err(BackendServerStartupError::Exited {
+                    status,
+                    output: self.captured_output(),
+                });
+            }
+
+            if TcpStream::connect((host, port)).await.is_ok() {
+                return Ok(());
+            }
+
+            if start.elapsed() >= deadline {
+                return Err(BackendServerStartupError::TimedOut {
+                    deadline,
+                    output: self.captured_output(),
+                });
+            }
+
+            time::sleep(READINESS_POLL_INTERVAL).await;
+        }
+    }
+
+    fn mark_reaped(&mut self) {
+        self.is_reaped = true;
+        Self::unregister_process_id(self.child.id());
+    }
+
+    // Kill the server and its process group, then reap the process.
+    pub fn stop(&mut self) {
+        if self.is_stopped {
+            return;
+        }
+        if !self.is_reaped {
+            Self::terminate_process_group(self.child.id());
+            if let Ok(None) = self.child.try_wait() {
+                _ = self.child.kill();
+            }
+            _ = self.child.wait();
+            self.mark_reaped();
+        }
+        if let Some(data_directory) = self.data_directory.take() {
+            _ = std::fs::remove_dir_all(data_directory);
+        }
+        self.is_stopped = true;
+    }
+}
+
+impl Drop for BackendServer {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shell_command(script: &str) -> Command {
+        let mut command = Command::new("sh");
+        command.arg("-c").arg(script);
+        command
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_ready_detects_early_exit() {
+        let mut server = BackendServer::launch(
+            shell_command("echo 'thread main panicked' >&2; exit 101"),
+            Path::new("."),
+        ).expect("Failed to launch test process.");
+
+        let result = server.wait_until_ready("127.0.0.1", 1, Duration::from_secs(5)).await;
+        match result {
+            Err(BackendServerStartupError::Exited { status, output }) => {
+                assert_eq!(status.code(), Some(101));
+                assert!(output.contains("thread main panicked"));
+            },
+            other => panic!("Unexpected readiness result: {:?}", other),
+        }
+
+        // The exited child was reaped, so its process ID is no longer tracked for the group kill.
+        assert!(server.is_reaped);
+        let process_id = server.child.id();
+        assert!(!RUNNING_SERVER_PROCESS_IDS.lock().unwrap().contains(&process_id));
+        server.stop();
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_ready_times_out() {
+        let mut server = BackendServer::launch(shell_command("sleep 5"), Path::new("."))
+            .expect("Failed to launch test process.");
+
+        let result = server.wait_until_ready("127.0.0.1", 1, Duration::from_millis(300)).await;
+        assert!(matches!(result, Err(BackendServerStartupError::TimedOut { .. })));
+        server.stop();
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_drop_terminates_process_group() {
+        let server = BackendServer::launch(shell_command("sleep 30 & echo $!; wait"), Path::new("."))
+            .expect("Failed to launch test process.");
+
+        // Wait for the shell to report its background child.
+        let mut grandchild_output = String::new();
+        for _ in 0..20 {
+            grandchild_output = server.captured_output();
+            if !grandchild_output.is_empty() {
+                break;
+            }
+            time::sleep(Duration::from_millis(50)).await;
+        }
+        let grandchild_process_id: u32 = grandchild_output
+            .trim()
+            .parse()
+            .expect("Failed to read background process ID.");
+
+        drop(server);
+        time::sleep(Duration::from_millis(100)).await;
+
+        // The grandchild is either gone, or a zombie waiting for init to reap it.
+        let process_stat = std::fs::read_to_string(format!("/proc/{}/stat", grandchild_process_id))
+            .unwrap_or_default();
+        assert!(process_stat.is_empty() || process_stat.contains(") Z "));
+    }
+}
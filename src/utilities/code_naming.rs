@@ -0,0 +1,64 @@
+/*
+ *  utilities/code_naming.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+// Join the alphanumeric words of a name in UpperCamelCase, for names in generated code.
+pub fn upper_camel_case(name: &str) -> String {
+    name.split(|character: char| !character.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut characters = word.chars();
+            match characters.next() {
+                Some(first_character) => format!("{}{}", first_character.to_ascii_uppercase(), characters.as_str()),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+// Convert an UpperCamelCase or lowerCamelCase name into snake_case, keeping acronyms such as `ID` in one word.
+pub fn snake_case(name: &str) -> String {
+    let characters: Vec<char> = name.chars().collect();
+    let mut snake_case_name = String::new();
+    for (index, &character) in characters.iter().enumerate() {
+        if character.is_ascii_uppercase() && index > 0 {
+            let previous_character = characters[index - 1];
+            let is_followed_by_lowercase = characters.get(index + 1).is_some_and(|next_character| next_character.is_ascii_lowercase());
+            if previous_character.is_ascii_lowercase()
+                || previous_character.is_ascii_digit()
+                || (previous_character.is_ascii_uppercase() && is_followed_by_lowercase) {
+                snake_case_name.push('_');
+            }
+        }
+        snake_case_name.push(character.to_ascii_lowercase());
+    }
+    snake_case_name
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upper_camel_case() {
+        assert_eq!(upper_camel_case("created-at"), "CreatedAt");
+        assert_eq!(upper_camel_case("/user/{user_id}"), "UserUserId");
+        assert_eq!(upper_camel_case(""), "");
+    }
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(snake_case("GetTaskId"), "get_task_id");
+        assert_eq!(snake_case("createdAt"), "created_at");
+        assert_eq!(snake_case("userID"), "user_id");
+        assert_eq!(snake_case("APIKey2Factor"), "api_key2_factor");
+        assert_eq!(snake_case("created_at"), "created_at");
+    }
+}
@@ -7,9 +7,15 @@
  *
  */
 
+use super::event_bus::{ AgentEvent, EventBus };
+
 use crossterm::style::{ Color, ResetColor, SetForegroundColor };
 use crossterm::ExecutableCommand;
-use std::io::{ stdin, stdout };
+use std::io::{ stdin, stdout, IsTerminal };
+use std::sync::atomic::{ AtomicBool, Ordering };
+
+// Whether the user approved running generated code up front, such as with `--yes`.
+static IS_GENERATED_CODE_APPROVED: AtomicBool = AtomicBool::new(false);
 
 #[derive(PartialEq, Debug)]
 enum CommandLineStatementKind {
@@ -84,13 +90,18 @@ impl CommandLine {
 
     }
 
+    // Determine whether a person is at the terminal to answer questions.
+    pub fn is_interactive() -> bool {
+        stdin().is_terminal() && stdout().is_terminal()
+    }
+
     pub fn get_user_response(question: &str) -> String {
         let mut stdout = stdout();
 
         // Print the question in a specific color.
         stdout.execute(SetForegroundColor(Color::Blue))
             .expect("Unable to set foreground color for getting user input from the command line.");
-        println!("");
+        println!();
         println!("{}", question);
 
         // Reset color.
@@ -104,18 +115,40 @@ impl CommandLine {
             .expect("Failed to read user response");
 
         // Trim whitespace and return.
-        return user_response.trim().to_string();
+        user_response.trim().to_string()
+    }
+
+    // Run generated code without asking, as needed when nobody is at the terminal.
+    pub fn approve_generated_code() {
+        IS_GENERATED_CODE_APPROVED.store(true, Ordering::SeqCst);
     }
 
     // Get user response that code is safe to execute.
+    // Without a terminal, such as in CI, generated code only runs when it was approved up front.
     pub fn confirm_safe_code() -> bool {
+        let is_safe_code = if IS_GENERATED_CODE_APPROVED.load(Ordering::SeqCst) {
+            true
+        } else if Self::is_interactive() {
+            Self::ask_whether_code_is_safe()
+        } else {
+            println!("Nobody is at the terminal to confirm running code written entirely by AI, so it does not run.");
+            false
+        };
+        EventBus::publish(AgentEvent::UserDecision {
+            question: "Run code written entirely by AI?".to_string(),
+            decision: if is_safe_code { "approved" } else { "declined" }.to_string(),
+        });
+        is_safe_code
+    }
+
+    fn ask_whether_code_is_safe() -> bool {
         let mut stdout = stdout();
         loop {
 
             // Print the question in specified color
             stdout.execute(SetForegroundColor(Color::Blue))
                 .expect("Unable to set foreground color for confirming whether generated code is safe to execute.");
-            println!("");
+            println!();
             print!("WARNING: You are about to run code written entirely by AI. ");
             println!("Review your code and confirm you wish to continue.");
 
@@ -135,11 +168,12 @@ impl CommandLine {
             stdout.execute(ResetColor)
                 .expect("Unable to reset color for confirming whether generated code is safe to execute.");
 
-            // Read user input.
+            // Read user input, which ends for good when stdin is closed.
             let mut user_response = String::new();
-            stdin()
-                .read_line(&mut user_response)
-                .expect("Failed to read response");
+            match stdin().read_line(&mut user_response) {
+                Ok(0) | Err(_) => return false,
+                Ok(_) => {},
+            }
 
             // Trim whitespace and convert to lowercase.
             let sanitized_user_response = user_response.trim().to_lowercase();
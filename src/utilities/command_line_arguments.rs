@@ -0,0 +1,129 @@
+/*
+ *  utilities/command_line_arguments.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::run_directory::DEFAULT_RUNS_DIRECTORY;
+
+use crate::api::open_ai::request::Model;
+use crate::model::pipeline_configuration::DEFAULT_PIPELINE_CONFIGURATION_PATH;
+
+use clap::{ ArgAction, Parser, Subcommand };
+use std::path::PathBuf;
+
+// What to run, and the flags that apply to every subcommand.
+#[derive(Debug, Parser)]
+#[command(name = "rust-auto-gpt-coder", version, about = "Agents that build a website from a prompt.")]
+pub struct CommandLineArguments {
+    /// Pipeline configuration choosing the agents and their options.
+    #[arg(long, global = true, value_name = "PATH", default_value = DEFAULT_PIPELINE_CONFIGURATION_PATH)]
+    pub config: PathBuf,
+
+    /// OpenAI model for every LLM call, such as gpt-5-mini.
+    #[arg(long, global = true, value_parser = Model::parse)]
+    pub model: Option<Model>,
+
+    /// Directory where each run saves its transcript, state, events and report.
+    #[arg(long, global = true, value_name = "PATH", default_value = DEFAULT_RUNS_DIRECTORY)]
+    pub output_dir: PathBuf,
+
+    /// Log more: -v for info, -vv for debug, -vvv for traces.
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Only log errors.
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Run generated code without asking first, as needed when nobody is at the terminal.
+    #[arg(short, long, global = true)]
+    pub yes: bool,
+
+    #[command(subcommand)]
+    pub command: Option<CommandLineCommand>,
+}
+
+#[derive(Debug, Clone, PartialEq, Subcommand)]
+pub enum CommandLineCommand {
+    /// Build a website from a prompt, which is asked for when missing. This is the default.
+    New {
+        prompt: Vec<String>,
+    },
+
+    /// Continue a run from its state file, or its run directory.
+    Resume {
+        state: PathBuf,
+    },
+
+    /// Build an existing backend project, fixing its code until it compiles.
+    Fix {
+        project_dir: PathBuf,
+    },
+
+    /// Build an existing backend project and test its endpoints on a running server.
+    Test {
+        project_dir: PathBuf,
+    },
+
+    /// Extract the endpoint schema of an existing backend project, with its OpenAPI document.
+    Schema {
+        project_dir: PathBuf,
+    },
+
+    /// Write the report of a past run again, from the state and events in its run directory.
+    Report {
+        run_dir: PathBuf,
+    },
+
+    /// List past runs, the LLM exchanges of a run, or one exchange.
+    Transcript {
+        run_id: Option<String>,
+        step: Option<u32>,
+    },
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_command_line_arguments() {
+        CommandLineArguments::command().debug_assert();
+
+        let arguments = CommandLineArguments::try_parse_from(["rust-auto-gpt-coder"])
+            .expect("Failed to parse arguments.");
+        assert_eq!(arguments.command, None);
+        assert_eq!(arguments.config, PathBuf::from(DEFAULT_PIPELINE_CONFIGURATION_PATH));
+        assert_eq!(arguments.output_dir, PathBuf::from(DEFAULT_RUNS_DIRECTORY));
+        assert!(!arguments.yes);
+
+        let arguments = CommandLineArguments::try_parse_from([
+            "rust-auto-gpt-coder", "new", "a", "task", "tracker", "--model", "gpt-5-mini", "-vv",
+        ]).expect("Failed to parse arguments.");
+        assert_eq!(arguments.command, Some(CommandLineCommand::New {
+            prompt: vec!["a".to_string(), "task".to_string(), "tracker".to_string()],
+        }));
+        assert_eq!(arguments.model, Some(Model::GPT_5_Mini));
+        assert_eq!(arguments.verbose, 2);
+
+        let arguments = CommandLineArguments::try_parse_from([
+            "rust-auto-gpt-coder", "test", "backend_code", "--output-dir", "out", "--yes",
+        ]).expect("Failed to parse arguments.");
+        assert_eq!(arguments.output_dir, PathBuf::from("out"));
+        assert!(arguments.yes);
+        assert_eq!(arguments.command, Some(CommandLineCommand::Test {
+            project_dir: PathBuf::from("backend_code"),
+        }));
+
+        assert!(CommandLineArguments::try_parse_from(["rust-auto-gpt-coder", "--model", "gpt-2", "new"]).is_err());
+        assert!(CommandLineArguments::try_parse_from(["rust-auto-gpt-coder", "-q", "-v", "new"]).is_err());
+        assert!(CommandLineArguments::try_parse_from(["rust-auto-gpt-coder", "transcript", "1", "two"]).is_err());
+    }
+}
@@ -0,0 +1,391 @@
+/*
+ *  utilities/endpoint_testing.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::networking::send_request;
+use crate::model::endpoint_route::{ EndpointRoute, HTTPMethod };
+use crate::model::field_type::FieldType;
+use crate::model::route_path::RoutePath;
+
+use reqwest::Client;
+use serde_json::{ Map, Value };
+use std::collections::HashMap;
+
+// Identifier used for resources created by the tests, unlikely to clash with real data.
+pub const SYNTHESIZED_RESOURCE_ID: u64 = 4242;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointTestStep {
+    pub endpoint: EndpointRoute,
+    pub resource: String,
+    pub request_body: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaMismatch {
+    pub path: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointTestResult {
+    pub method: HTTPMethod,
+    pub route: String,
+    pub url: String,
+    pub status_code: Option<u16>,
+    pub response_body: Option<String>,
+    pub schema_mismatches: Vec<SchemaMismatch>,
+    pub passed: bool,
+    pub detail: String,
+}
+
+impl EndpointTestResult {
+    // Describe the failure in a form the developer agent can act on.
+    pub fn failure_description(&self) -> String {
+        let mut description = format!(
+            "Endpoint {:?} {} (called as {}) failed: {}.", 
+            self.method, self.route, self.url, self.detail, 
+        );
+        for mismatch in &self.schema_mismatches {
+            description.push_str(&format!(
+                "\n  - at `{}` expected {} but got {}", 
+                mismatch.path, mismatch.expected, mismatch.actual, 
+            ));
+        }
+        if let Some(response_body) = &self.response_body {
+            description.push_str(&format!("\n  Response body: {}", response_body));
+        }
+        description
+    }
+}
+
+// Get the resource an endpoint acts on: its route up to the first dynamic segment.
+fn resource_for_route(route: &str) -> String {
+    RoutePath::parse(route).static_prefix()
+}
+
+// Rank endpoints so each resource is created, read, updated, then deleted.
+fn crud_rank(endpoint: &EndpointRoute) -> u8 {
+    match endpoint.method {
+        HTTPMethod::Post => 0,
+        HTTPMethod::Get | HTTPMethod::Head | HTTPMethod::Options if !endpoint.is_route_dynamic => 1,
+        HTTPMethod::Get | HTTPMethod::Head | HTTPMethod::Options => 2,
+        HTTPMethod::Put | HTTPMethod::Patch => 3,
+        HTTPMethod::Delete => 4,
+    }
+}
+
+// Describe the JSON type of a value for mismatch reports.
+fn json_type_name(value: &Value) -> String {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }.to_string()
+}
+
+// Compare a response value against its declared type, collecting every mismatch.
+pub fn validate_response(declared: &FieldType, actual: &Value, path: &str) -> Vec<SchemaMismatch> {
+    let mismatch = |expected: &str| vec![SchemaMismatch {
+        path: path.to_string(),
+        expected: expected.to_string(),
+        actual: json_type_name(actual),
+    }];
+
+    match (declared, actual) {
+        (FieldType::Any, _) => vec![],
+        (FieldType::Nullable(_), Value::Null) => vec![],
+        (FieldType::Nullable(inner_type), _) => validate_response(inner_type, actual, path),
+        (FieldType::Integer, Value::Number(number)) if number.is_i64() || number.is_u64() => vec![],
+        (FieldType::Number, Value::Number(_)) => vec![],
+        (FieldType::String, Value::String(_)) => vec![],
+        (FieldType::Bool, Value::Bool(_)) => vec![],
+
+        // Lists are often declared by their element type alone.
+        (FieldType::Object(_), Value::Array(elements)) | (FieldType::Array(_), Value::Array(elements)) => {
+            let element_type = match declared {
+                FieldType::Array(element_type) => element_type.as_ref(),
+                _ => declared,
+            };
+            elements
+                .iter()
+                .enumerate()
+                .flat_map(|(index, element)| validate_response(element_type, element, &format!("{}[{}]", path, index)))
+                .collect()
+        },
+
+        (FieldType::Object(field_types), Value::Object(fields)) => field_types
+            .iter()
+            .flat_map(|(name, field_type)| {
+                let field_path = format!("{}.{}", path, name);
+                match (fields.get(name), field_type) {
+                    (Some(field), _) => validate_response(field_type, field, &field_path),
+                    (None, FieldType::Nullable(_)) => vec![],
+                    (None, _) => vec![SchemaMismatch {
+                        path: field_path,
+                        expected: "field to be present".to_string(),
+                        actual: "missing field".to_string(),
+                    }],
+                }
+            })
+            .collect(),
+
+        (FieldType::Integer, _) => mismatch("integer"),
+        (FieldType::Number, _) => mismatch("number"),
+        (FieldType::String, _) => mismatch("string"),
+        (FieldType::Bool, _) => mismatch("bool"),
+        (FieldType::Object(_), _) => mismatch("object"),
+        (FieldType::Array(_), _) => mismatch("array"),
+    }
+}
+
+// Validate a raw response body against an endpoint's declared response.
+// HEAD responses have no body by protocol, so only their status code is checked.
+fn validate_response_body(endpoint: &EndpointRoute, response_body: &str) -> Vec<SchemaMismatch> {
+    if endpoint.method == HTTPMethod::Head {
+        return vec![];
+    }
+    let Some(response_type) = &endpoint.response else {
+        return vec![];
+    };
+    match serde_json::from_str::<Value>(response_body) {
+        Ok(response_json) => validate_response(response_type, &response_json, "$"),
+        Err(_) => vec![SchemaMismatch {
+            path: "$".to_string(),
+            expected: "a JSON body".to_string(),
+            actual: format!("non-JSON body {:?}", response_body),
+        }],
+    }
+}
+
+// Build a sample value matching a declared field type.
+pub fn synthesize_value(field_name: &str, field_type: &FieldType, resource_id: u64) -> Value {
+    match field_type {
+        FieldType::Integer | FieldType::Number => {
+            if field_name == "id" || field_name.ends_with("_id") {
+                Value::from(resource_id)
+            } else {
+                Value::from(1)
+            }
+        },
+        FieldType::Bool => Value::Bool(true),
+        FieldType::String | FieldType::Any => Value::String(format!("test_{}", field_name)),
+        FieldType::Nullable(inner_type) => synthesize_value(field_name, inner_type, resource_id),
+        FieldType::Array(element_type) => Value::Array(vec![synthesize_value(field_name, element_type, resource_id)]),
+        FieldType::Object(field_types) => {
+            let fields: Map<String, Value> = field_types
+                .iter()
+                .map(|(name, field_type)| (name.clone(), synthesize_value(name, field_type, resource_id)))
+                .collect();
+            Value::Object(fields)
+        },
+    }
+}
+
+// Build the request payload for an endpoint, if it declares a body.
+fn synthesize_request_body(endpoint: &EndpointRoute) -> Option<Value> {
+    endpoint
+        .request_body
+        .as_ref()
+        .map(|request_body| synthesize_value("", request_body, SYNTHESIZED_RESOURCE_ID))
+}
+
+// Order all endpoints into create → read → update → delete sequences per resource.
+pub fn plan_endpoint_tests(endpoints: &[EndpointRoute]) -> Vec<EndpointTestStep> {
+    let mut resources: Vec<String> = vec![];
+    for endpoint in endpoints {
+        let resource = resource_for_route(&endpoint.route);
+        if !resources.contains(&resource) {
+            resources.push(resource);
+        }
+    }
+
+    let mut steps: Vec<EndpointTestStep> = vec![];
+    for resource in resources {
+        let mut resource_endpoints: Vec<&EndpointRoute> = endpoints
+            .iter()
+            .filter(|endpoint| resource_for_route(&endpoint.route) == resource)
+            .collect();
+        resource_endpoints.sort_by_key(|endpoint| crud_rank(endpoint));
+
+        steps.extend(resource_endpoints.into_iter().map(|endpoint| EndpointTestStep {
+            endpoint: endpoint.clone(),
+            resource: resource.clone(),
+            request_body: synthesize_request_body(endpoint),
+        }));
+    }
+    steps
+}
+
+// Replace every parameter of a route with the given identifier, and any wildcard tail with a sample path.
+fn fill_route_parameters(route: &str, resource_id: u64) -> String {
+    RoutePath::parse(route).fill(&resource_id.to_string(), "test")
+}
+
+// Run the planned steps against a live server, reusing IDs returned by earlier steps.
+pub async fn run_endpoint_tests(
+    client: &Client,
+    base_url: &str,
+    steps: &[EndpointTestStep],
+) -> Vec<EndpointTestResult> {
+    let mut resource_ids: HashMap<String, u64> = HashMap::new();
+    let mut results: Vec<EndpointTestResult> = vec![];
+
+    for step in steps {
+        let resource_id = *resource_ids
+            .get(&step.resource)
+            .unwrap_or(&SYNTHESIZED_RESOURCE_ID);
+        let url = format!("{}{}", base_url, fill_route_parameters(&step.endpoint.route, resource_id));
+
+        let result = match send_request(client, &step.endpoint.method, &url, step.request_body.as_ref()).await {
+            Ok((status_code, response_body)) => {
+
+                // Remember an ID assigned by the server for later steps on this resource.
+                if let Ok(response_json) = serde_json::from_str::<Value>(&response_body)
+                    && let Some(created_id) = response_json.get("id").and_then(Value::as_u64)
+                {
+                    resource_ids.insert(step.resource.clone(), created_id);
+                }
+
+                let is_successful = step.endpoint.accepts_status_code(status_code);
+                let schema_mismatches = if is_successful {
+                    validate_response_body(&step.endpoint, &response_body)
+                } else {
+                    vec![]
+                };
+                let detail = if !is_successful {
+                    format!("Responded with unexpected status code {}", status_code)
+                } else if !schema_mismatches.is_empty() {
+                    format!("Response does not match the declared schema ({} mismatches)", schema_mismatches.len())
+                } else {
+                    format!("Responded with status code {}", status_code)
+                };
+                EndpointTestResult {
+                    method: step.endpoint.method.clone(),
+                    route: step.endpoint.route.clone(),
+                    url,
+                    status_code: Some(status_code),
+                    response_body: Some(response_body),
+                    passed: is_successful && schema_mismatches.is_empty(),
+                    schema_mismatches,
+                    detail,
+                }
+            },
+            Err(error) => EndpointTestResult {
+                method: step.endpoint.method.clone(),
+                route: step.endpoint.route.clone(),
+                url,
+                status_code: None,
+                response_body: None,
+                schema_mismatches: vec![],
+                passed: false,
+                detail: format!("Request failed: {}", error),
+            },
+        };
+        results.push(result);
+    }
+
+    results
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(method: HTTPMethod, route: &str, request_body: Option<FieldType>) -> EndpointRoute {
+        EndpointRoute::new(method, route.to_string(), request_body, None)
+    }
+
+    fn task_type() -> FieldType {
+        FieldType::from_value(&serde_json::json!({ "id": "number", "name": "string", "completed": "bool" }))
+            .expect("Failed to decode task type.")
+    }
+
+    #[test]
+    fn test_plan_orders_crud_sequences() {
+        let endpoints = vec![
+            endpoint(HTTPMethod::Delete, "/task/{id}", None),
+            endpoint(HTTPMethod::Get, "/task/{id}", None),
+            endpoint(HTTPMethod::Put, "/task", Some(task_type())),
+            endpoint(HTTPMethod::Get, "/task", None),
+            endpoint(HTTPMethod::Post, "/task", Some(task_type())),
+            endpoint(HTTPMethod::Get, "/health", None),
+        ];
+
+        let steps = plan_endpoint_tests(&endpoints);
+        let order: Vec<(HTTPMethod, &str)> = steps
+            .iter()
+            .map(|step| (step.endpoint.method.clone(), step.endpoint.route.as_str()))
+            .collect();
+        assert_eq!(order, vec![
+            (HTTPMethod::Post, "/task"),
+            (HTTPMethod::Get, "/task"),
+            (HTTPMethod::Get, "/task/{id}"),
+            (HTTPMethod::Put, "/task"),
+            (HTTPMethod::Delete, "/task/{id}"),
+            (HTTPMethod::Get, "/health"),
+        ]);
+
+        assert_eq!(
+            steps[0].request_body,
+            Some(serde_json::json!({ "id": SYNTHESIZED_RESOURCE_ID, "name": "test_name", "completed": true })),
+        );
+        assert_eq!(steps[1].request_body, None);
+    }
+
+    #[test]
+    fn test_fill_route_parameters() {
+        assert_eq!(fill_route_parameters("/task/{id}", 7), "/task/7");
+        assert_eq!(fill_route_parameters("/task", 7), "/task");
+        assert_eq!(fill_route_parameters("/files/{path:.*}", 7), "/files/test");
+        assert_eq!(resource_for_route("/task/{id}"), "/task");
+        assert_eq!(resource_for_route("/{id}"), "/");
+    }
+
+    #[test]
+    fn test_validate_response() {
+        let declared = task_type();
+
+        let valid = serde_json::json!({ "id": 1, "name": "Groceries", "completed": false, "extra": 3 });
+        assert!(validate_response(&declared, &valid, "$").is_empty());
+
+        let valid_list = serde_json::json!([{ "id": 1, "name": "Groceries", "completed": false }]);
+        assert!(validate_response(&declared, &valid_list, "$").is_empty());
+
+        let invalid = serde_json::json!([{ "id": "1", "completed": false }]);
+        let mismatches = validate_response(&declared, &invalid, "$");
+        assert_eq!(mismatches, vec![
+            SchemaMismatch {
+                path: "$[0].id".to_string(),
+                expected: "number".to_string(),
+                actual: "string".to_string(),
+            },
+            SchemaMismatch {
+                path: "$[0].name".to_string(),
+                expected: "field to be present".to_string(),
+                actual: "missing field".to_string(),
+            },
+        ]);
+    }
+
+    #[test]
+    fn test_validate_response_body() {
+        let get_endpoint = EndpointRoute::new(HTTPMethod::Get, "/task/{id}".to_string(), None, Some(task_type()));
+        assert_eq!(validate_response_body(&get_endpoint, "").len(), 1);
+        assert!(validate_response_body(&get_endpoint, r#"{ "id": 1, "name": "Groceries", "completed": false }"#).is_empty());
+
+        let head_endpoint = EndpointRoute::new(HTTPMethod::Head, "/task/{id}".to_string(), None, Some(task_type()));
+        assert!(validate_response_body(&head_endpoint, "").is_empty());
+    }
+}
@@ -0,0 +1,383 @@
+/*
+ *  utilities/event_bus.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::command_line::CommandLine;
+
+use crate::agents::common::attributes::AgentStatus;
+use crate::agents::common::report::AgentReport;
+use crate::api::open_ai::request::Model;
+use crate::api::open_ai::response::APIUsage;
+use crate::model::endpoint_route::HTTPMethod;
+
+use serde::{ Deserialize, Serialize };
+use std::fs::{ self, File, OpenOptions };
+use std::io::{ BufRead, BufReader, Write };
+use std::path::Path;
+use std::sync::{ Arc, Mutex, RwLock };
+use std::sync::atomic::{ AtomicU64, Ordering };
+use std::time::{ SystemTime, UNIX_EPOCH };
+use tokio::sync::mpsc::{ unbounded_channel, UnboundedReceiver, UnboundedSender };
+
+// What an agent's progress message is about, which the terminal shows in its own color.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AgentMessageKind {
+    Generation,
+    UnitTest,
+    Issue,
+}
+
+// Something that happened while agents built the project.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum AgentEvent {
+    AgentStarted {
+        position: String,
+    },
+    AgentMessage {
+        position: String,
+        kind: AgentMessageKind,
+        message: String,
+    },
+    AgentFinished {
+        position: String,
+        report: Option<AgentReport>,
+        error: Option<String>,
+    },
+    AgentSkipped {
+        position: String,
+    },
+    StatusChanged {
+        position: String,
+        status: AgentStatus,
+    },
+    LlmCallStarted {
+        position: String,
+        operation: String,
+    },
+    LlmCallFinished {
+        position: String,
+        operation: String,
+        #[serde(default)]
+        model: Option<Model>,
+        succeeded: bool,
+        usage: Option<APIUsage>,
+    },
+    ExternalUrlChecked {
+        position: String,
+        url: String,
+        status_code: Option<u16>,
+        excluded: bool,
+    },
+    BugRecorded {
+        position: String,
+        iteration: u8,
+        diagnostics: String,
+    },
+    BuildFinished {
+        position: String,
+        target: String,
+        succeeded: bool,
+    },
+    EndpointTested {
+        position: String,
+        method: HTTPMethod,
+        route: String,
+        passed: bool,
+        detail: String,
+    },
+    UserDecision {
+        question: String,
+        decision: String,
+    },
+}
+
+// An event with the time it was published, as written to event logs.
+#[derive(Debug, Serialize)]
+struct EventRecord<'a> {
+    timestamp_ms: u128,
+    #[serde(flatten)]
+    event: &'a AgentEvent,
+}
+
+// Receives every event published on the bus, on the thread that published it.
+pub trait EventSink: Send + Sync {
+    fn handle_event(&self, event: &AgentEvent);
+}
+
+// Sinks with the identifiers they were subscribed under, so subscriptions can remove them.
+static EVENT_SINKS: RwLock<Vec<(u64, Arc<dyn EventSink>)>> = RwLock::new(Vec::new());
+static NEXT_SINK_ID: AtomicU64 = AtomicU64::new(0);
+
+pub struct EventBus {}
+
+impl EventBus {
+    // Subscribe a sink for the rest of the program.
+    pub fn subscribe(sink: Arc<dyn EventSink>) {
+        Self::add_sink(sink);
+    }
+
+    // Subscribe with a channel, for consumers that process events on their own task, until the subscription is dropped.
+    pub fn subscribe_channel() -> EventSubscription {
+        let (sender, receiver) = unbounded_channel();
+        let sink_id = Self::add_sink(Arc::new(ChannelEventSink { sender }));
+        EventSubscription { sink_id, receiver }
+    }
+
+    fn add_sink(sink: Arc<dyn EventSink>) -> u64 {
+        let sink_id = NEXT_SINK_ID.fetch_add(1, Ordering::Relaxed);
+        EVENT_SINKS.write()
+            .expect("Event sinks lock is poisoned.")
+            .push((sink_id, sink));
+        sink_id
+    }
+
+    fn remove_sink(sink_id: u64) {
+        if let Ok(mut sinks) = EVENT_SINKS.write() {
+            sinks.retain(|(subscribed_sink_id, _)| *subscribed_sink_id != sink_id);
+        }
+    }
+
+    // Dispatch outside the lock, so a sink may subscribe or unsubscribe, or take its time, without blocking the bus.
+    pub fn publish(event: AgentEvent) {
+        let sinks: Vec<Arc<dyn EventSink>> = EVENT_SINKS.read()
+            .expect("Event sinks lock is poisoned.")
+            .iter()
+            .map(|(_, sink)| Arc::clone(sink))
+            .collect();
+        for sink in sinks {
+            sink.handle_event(&event);
+        }
+    }
+
+    pub fn publish_generation_message(position: &str, message: &str) {
+        Self::publish_agent_message(position, AgentMessageKind::Generation, message);
+    }
+
+    pub fn publish_unit_test_message(position: &str, message: &str) {
+        Self::publish_agent_message(position, AgentMessageKind::UnitTest, message);
+    }
+
+    pub fn publish_issue_message(position: &str, message: &str) {
+        Self::publish_agent_message(position, AgentMessageKind::Issue, message);
+    }
+
+    // Report an agent's progress, which the terminal shows and event logs keep.
+    fn publish_agent_message(position: &str, kind: AgentMessageKind, message: &str) {
+        Self::publish(AgentEvent::AgentMessage {
+            position: position.to_string(),
+            kind,
+            message: message.to_string(),
+        });
+    }
+}
+
+// Events published since subscribing, which stop arriving once this is dropped.
+pub struct EventSubscription {
+    sink_id: u64,
+    receiver: UnboundedReceiver<AgentEvent>,
+}
+
+impl EventSubscription {
+    // Wait for the next event.
+    pub async fn recv(&mut self) -> Option<AgentEvent> {
+        self.receiver.recv().await
+    }
+
+    // Get the next event, if one was already published.
+    pub fn try_recv(&mut self) -> Option<AgentEvent> {
+        self.receiver.try_recv().ok()
+    }
+}
+
+impl Drop for EventSubscription {
+    fn drop(&mut self) {
+        EventBus::remove_sink(self.sink_id);
+    }
+}
+
+// Renders the events that make up an agent's progress in the terminal.
+// Only questions to the user, and where a run saved its files, are printed without going through the bus.
+pub struct TerminalEventSink {
+    // Whether to only show issues, as with `--quiet`.
+    pub is_quiet: bool,
+}
+
+impl EventSink for TerminalEventSink {
+    fn handle_event(&self, event: &AgentEvent) {
+        match event {
+            AgentEvent::AgentMessage { position, kind, message } => match kind {
+                AgentMessageKind::Generation if !self.is_quiet => {
+                    CommandLine::print_agent_generation_message(position, message);
+                },
+                AgentMessageKind::UnitTest if !self.is_quiet => {
+                    CommandLine::print_agent_unit_test_message(position, message);
+                },
+                AgentMessageKind::Issue => {
+                    CommandLine::print_agent_error_message(position, message);
+                },
+                _ => {},
+            },
+            AgentEvent::LlmCallStarted { position, operation } if !self.is_quiet => {
+                CommandLine::print_agent_generation_message(position, operation);
+            },
+            AgentEvent::EndpointTested { position, method, route, passed, detail } if !*passed || !self.is_quiet => {
+                let result_message = format!(
+                    "{} {:?} {}: {}", 
+                    if *passed { "PASS" } else { "FAIL" }, 
+                    method, route, detail, 
+                );
+                if *passed {
+                    CommandLine::print_agent_unit_test_message(position, result_message.as_str());
+                } else {
+                    CommandLine::print_agent_error_message(position, result_message.as_str());
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+// Appends each event as one line of JSON, so runs can be inspected and replayed.
+pub struct JsonLinesEventSink {
+    file: Mutex<File>,
+}
+
+impl JsonLinesEventSink {
+    pub fn create(path: &Path) -> std::io::Result<Self> {
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+
+    // Read back the events of a log, skipping lines that are not events.
+    pub fn read_events(path: &Path) -> std::io::Result<Vec<AgentEvent>> {
+        let file = File::open(path)?;
+        let mut events = vec![];
+        for line in BufReader::new(file).lines() {
+            if let Ok(event) = serde_json::from_str(&line?) {
+                events.push(event);
+            }
+        }
+        Ok(events)
+    }
+}
+
+impl EventSink for JsonLinesEventSink {
+    fn handle_event(&self, event: &AgentEvent) {
+        let timestamp_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_millis());
+        let record = EventRecord {
+            timestamp_ms,
+            event,
+        };
+        let Ok(line) = serde_json::to_string(&record) else {
+            return;
+        };
+        if let Ok(mut file) = self.file.lock() {
+            _ = writeln!(file, "{}", line);
+        }
+    }
+}
+
+struct ChannelEventSink {
+    sender: UnboundedSender<AgentEvent>,
+}
+
+impl EventSink for ChannelEventSink {
+    fn handle_event(&self, event: &AgentEvent) {
+        _ = self.sender.send(event.clone());
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_bus() {
+        let mut subscription = EventBus::subscribe_channel();
+        let sink_id = subscription.sink_id;
+        let event = AgentEvent::UserDecision {
+            question: format!("Run the event bus test with sink {}?", sink_id),
+            decision: "approved".to_string(),
+        };
+        EventBus::publish(event.clone());
+
+        // Other tests publish on the same bus, so only count this test's event, which is delivered once.
+        let mut received_events = vec![];
+        while let Some(received_event) = subscription.try_recv() {
+            received_events.push(received_event);
+        }
+        assert_eq!(received_events.iter().filter(|&received_event| *received_event == event).count(), 1);
+
+        // Dropping the subscription removes its sink from the bus.
+        drop(subscription);
+        let is_subscribed = EVENT_SINKS.read()
+            .expect("Event sinks lock is poisoned.")
+            .iter()
+            .any(|(subscribed_sink_id, _)| *subscribed_sink_id == sink_id);
+        assert!(!is_subscribed);
+        let event = AgentEvent::UserDecision {
+            question: "Run the event bus test?".to_string(),
+            decision: "approved".to_string(),
+        };
+
+        let record = EventRecord {
+            timestamp_ms: 42,
+            event: &event,
+        };
+        assert_eq!(
+            serde_json::to_string(&record).expect("Failed to encode event record."),
+            r#"{"timestamp_ms":42,"event":"user_decision","question":"Run the event bus test?","decision":"approved"}"#,
+        );
+        let decoded_event: AgentEvent = serde_json::from_str(&serde_json::to_string(&record).expect("Failed to encode event record."))
+            .expect("Failed to decode event record.");
+        assert_eq!(decoded_event, event);
+
+        let event = AgentEvent::AgentMessage {
+            position: "QA Engineer".to_string(),
+            kind: AgentMessageKind::UnitTest,
+            message: "QA Testing: 3 of 3 scenarios passed.".to_string(),
+        };
+        assert_eq!(
+            serde_json::to_string(&event).expect("Failed to encode event."),
+            r#"{"event":"agent_message","position":"QA Engineer","kind":"unit_test","message":"QA Testing: 3 of 3 scenarios passed."}"#,
+        );
+    }
+
+    // Subscribe and unsubscribe from within a sink, which needs the bus not to hold its lock while dispatching.
+    struct SubscribingEventSink {}
+
+    impl EventSink for SubscribingEventSink {
+        fn handle_event(&self, _event: &AgentEvent) {
+            drop(EventBus::subscribe_channel());
+        }
+    }
+
+    #[test]
+    fn test_event_bus_sink_subscribing() {
+        let sink_id = EventBus::add_sink(Arc::new(SubscribingEventSink {}));
+        EventBus::publish(AgentEvent::UserDecision {
+            question: "Subscribe from within a sink?".to_string(),
+            decision: "approved".to_string(),
+        });
+        EventBus::remove_sink(sink_id);
+    }
+}
@@ -0,0 +1,40 @@
+/*
+ *  utilities/frontend_code_persistence.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::backend_code_persistence::BackendCodePersistence;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+const FRONTEND_CODE_PROJECT_PATH: &str = "frontend";
+
+pub struct FrontendCodePersistence {}
+
+impl FrontendCodePersistence {
+    // Get frontend code project path, next to the backend project being worked on.
+    pub fn get_frontend_code_project_path() -> PathBuf {
+        BackendCodePersistence::get_backend_code_project_path().with_file_name(FRONTEND_CODE_PROJECT_PATH)
+    }
+
+    // Save new frontend files, replacing those of a previous generation.
+    pub fn save_frontend_code(files: &BTreeMap<String, String>) {
+        let frontend_code_project_path = Self::get_frontend_code_project_path();
+        if frontend_code_project_path.exists() {
+            fs::remove_dir_all(&frontend_code_project_path)
+                .expect("Failed to remove previous frontend files.");
+        }
+        fs::create_dir_all(&frontend_code_project_path)
+            .expect("Failed to create frontend directory.");
+        for (file_name, contents) in files {
+            fs::write(frontend_code_project_path.join(file_name), contents)
+                .expect("Failed to write frontend file.");
+        }
+    }
+}
@@ -0,0 +1,464 @@
+/*
+ *  utilities/frontend_smoke_check.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use crate::model::endpoint_route::{ EndpointRoute, HTTPMethod };
+use crate::model::route_path::{ RoutePath, RouteSegment };
+
+use reqwest::Client;
+use std::collections::{ BTreeMap, BTreeSet };
+use std::path::{ Path, PathBuf };
+use std::time::Duration;
+use tokio::io::{ AsyncReadExt, AsyncWriteExt };
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+pub const FRONTEND_ENTRY_PAGE: &str = "index.html";
+pub const FRONTEND_CONFIGURATION_FILE: &str = "config.js";
+const FRONTEND_FILE_EXTENSIONS: &[&str] = &[".html", ".css", ".js"];
+const FRONTEND_SERVER_HOST: &str = "127.0.0.1";
+const FRONTEND_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const MAXIMUM_REQUEST_HEAD_LENGTH: usize = 8192;
+const API_BASE_URL_VARIABLE: &str = "API_BASE_URL";
+
+// Check that a generated file name stays inside the frontend directory and is static content.
+pub fn is_valid_frontend_file_name(file_name: &str) -> bool {
+    !file_name.is_empty()
+        && file_name
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric() || matches!(character, '_' | '-' | '.'))
+        && !file_name.starts_with('.')
+        && FRONTEND_FILE_EXTENSIONS.iter().any(|extension| file_name.ends_with(extension))
+}
+
+// Collect the values of an attribute such as `src` or `href` in an HTML document.
+fn attribute_values(html: &str, attribute: &str) -> Vec<String> {
+    let mut values: Vec<String> = vec![];
+    for quote in ['"', '\''] {
+        let pattern = format!("{}={}", attribute, quote);
+        let mut remaining = html;
+        while let Some(start_index) = remaining.find(&pattern) {
+            let value_start = &remaining[start_index + pattern.len()..];
+            let Some(value_length) = value_start.find(quote) else {
+                break;
+            };
+            values.push(value_start[..value_length].to_string());
+            remaining = &value_start[value_length..];
+        }
+    }
+    values
+}
+
+fn is_local_reference(reference: &str) -> bool {
+    !reference.is_empty()
+        && !reference.starts_with('#')
+        && !reference.contains("://")
+        && !reference.starts_with("//")
+        && !reference.starts_with("data:")
+        && !reference.starts_with("mailto:")
+}
+
+// Get the file name a local `src` or `href` reference points to.
+fn referenced_file_name(reference: &str) -> &str {
+    reference.trim_start_matches("./").split(['?', '#']).next().unwrap_or_default()
+}
+
+// Collect the paths that code requests from the API, such as `/task/${id}` in `${window.API_BASE_URL}/task/${id}`.
+fn api_call_paths(code: &str) -> Vec<String> {
+    let mut paths: Vec<String> = vec![];
+    let mut remaining = code;
+    while let Some(start_index) = remaining.find(API_BASE_URL_VARIABLE) {
+        remaining = &remaining[start_index + API_BASE_URL_VARIABLE.len()..];
+        let path_start = remaining.trim_start_matches(|character: char| character == '}' || character == '+' || character.is_whitespace());
+        let path_start = path_start.trim_start_matches(['"', '\'', '`']);
+        if !path_start.starts_with('/') {
+            continue;
+        }
+
+        // The path runs until its string ends, keeping `${...}` interpolations whole.
+        let mut path = String::new();
+        let mut interpolation_depth = 0;
+        for character in path_start.chars() {
+            match character {
+                '{' if path.ends_with('$') || interpolation_depth > 0 => interpolation_depth += 1,
+                '}' if interpolation_depth > 0 => interpolation_depth -= 1,
+                '"' | '\'' | '`' | '?' | '#' | ')' | ',' | ';' if interpolation_depth == 0 => break,
+                character if character.is_whitespace() && interpolation_depth == 0 => break,
+                _ => {},
+            }
+            path.push(character);
+        }
+        paths.push(path);
+    }
+    paths
+}
+
+// Check whether a path requested by the page, with `${...}` interpolations or a value appended after a trailing `/`, fits a route.
+fn is_route_path_called(route_path: &RoutePath, path: &str) -> bool {
+    let path_segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    let is_dynamic = |path_segment: &str| path_segment.is_empty() || path_segment.contains("${");
+    let mut path_segments = path_segments.as_slice();
+    if path_segments == [""] {
+        path_segments = &[];
+    }
+    for (index, route_segment) in route_path.segments.iter().enumerate() {
+        match route_segment {
+            RouteSegment::Wildcard(_) => return true,
+            RouteSegment::Parameter(_) if index < path_segments.len() => {},
+            RouteSegment::Static(name) if index < path_segments.len() => {
+                if path_segments[index] != name && !is_dynamic(path_segments[index]) {
+                    return false;
+                }
+            },
+            _ => return false,
+        }
+    }
+    path_segments.len() == route_path.segments.len()
+}
+
+fn content_type(file_name: &str) -> &'static str {
+    match file_name.rsplit('.').next() {
+        Some("html") => "text/html; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("js") => "text/javascript; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
+
+// Check that each page loads its local assets and runs scripts that call the API, returning every problem found.
+pub fn smoke_check_frontend(files: &BTreeMap<String, String>, endpoints: &[EndpointRoute]) -> Vec<String> {
+    let mut problems: Vec<String> = vec![];
+
+    for file_name in files.keys() {
+        if !is_valid_frontend_file_name(file_name) {
+            problems.push(format!("`{}` is not a plain .html, .css or .js file name.", file_name));
+        }
+    }
+    if !files.contains_key(FRONTEND_ENTRY_PAGE) {
+        problems.push(format!("There is no `{}` entry page.", FRONTEND_ENTRY_PAGE));
+    }
+
+    let api_prefixes: Vec<String> = endpoints
+        .iter()
+        .map(|endpoint| endpoint.route_path().static_prefix())
+        .filter(|prefix| prefix != "/")
+        .collect();
+
+    for (page_name, page) in files.iter().filter(|(file_name, _)| file_name.ends_with(".html")) {
+        if !page.to_lowercase().contains("<html") {
+            problems.push(format!("`{}` is not an HTML document.", page_name));
+        }
+
+        let mut references = attribute_values(page, "src");
+        references.extend(attribute_values(page, "href"));
+        for reference in references.iter().filter(|reference| is_local_reference(reference)) {
+            let file_name = referenced_file_name(reference);
+            if !files.contains_key(file_name) {
+                problems.push(format!("`{}` references `{}`, which does not exist.", page_name, reference));
+            }
+        }
+
+        // The page's own scripts, inline or loaded, must call the API at the configured base URL.
+        let script_names: Vec<String> = attribute_values(page, "src")
+            .iter()
+            .map(|reference| reference.trim_start_matches("./").to_string())
+            .collect();
+        let scripts: Vec<&String> = script_names
+            .iter()
+            .filter_map(|script_name| files.get(script_name))
+            .collect();
+        if !script_names.iter().any(|script_name| script_name == FRONTEND_CONFIGURATION_FILE) {
+            problems.push(format!("`{}` does not load `{}`, which sets the API base URL.", page_name, FRONTEND_CONFIGURATION_FILE));
+        }
+        let calls_api = scripts
+            .iter()
+            .copied()
+            .chain(std::iter::once(page))
+            .any(|code| code.contains("fetch(") && (api_prefixes.is_empty() || api_prefixes.iter().any(|prefix| code.contains(prefix.as_str()))));
+        if !calls_api {
+            problems.push(format!("`{}` never calls the API with `fetch` on one of its routes.", page_name));
+        }
+    }
+
+    problems
+}
+
+// Serve the frontend directory over HTTP the way a static host would, returning the port and the server task.
+async fn serve_frontend_directory(frontend_directory: PathBuf) -> std::io::Result<(u16, JoinHandle<()>)> {
+    let listener = TcpListener::bind((FRONTEND_SERVER_HOST, 0)).await?;
+    let port = listener.local_addr()?.port();
+    let server = tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let frontend_directory = frontend_directory.clone();
+            tokio::spawn(async move {
+                let mut request_head = vec![0; MAXIMUM_REQUEST_HEAD_LENGTH];
+                let Ok(length) = stream.read(&mut request_head).await else { return };
+                let request_head = String::from_utf8_lossy(&request_head[..length]);
+                let path = request_head.split_whitespace().nth(1).unwrap_or("/");
+                let file_name = match referenced_file_name(path.trim_start_matches('/')) {
+                    "" => FRONTEND_ENTRY_PAGE,
+                    file_name => file_name,
+                };
+                let contents = match is_valid_frontend_file_name(file_name) {
+                    true => tokio::fs::read(frontend_directory.join(file_name)).await.ok(),
+                    false => None,
+                };
+                let (status_line, content_type, body) = match contents {
+                    Some(body) => ("200 OK", content_type(file_name), body),
+                    None => ("404 Not Found", "text/plain; charset=utf-8", b"Not Found".to_vec()),
+                };
+                let response_head = format!(
+                    "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    status_line, content_type, body.len(),
+                );
+                _ = stream.write_all(response_head.as_bytes()).await;
+                _ = stream.write_all(&body).await;
+            });
+        }
+    });
+    Ok((port, server))
+}
+
+// Get a served file, describing the problem when it does not load.
+async fn load_served_file(client: &Client, url: &str) -> Result<String, String> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|error| format!("`{}` did not load: {}.", url, error))?;
+    if !response.status().is_success() {
+        return Err(format!("`{}` did not load: status code {}.", url, response.status().as_u16()));
+    }
+    response
+        .text()
+        .await
+        .map_err(|error| format!("`{}` did not load: {}.", url, error))
+}
+
+// Call an endpoint the page uses on the running backend, from the page's origin so CORS applies as in a browser.
+async fn call_api_from_page(
+    client: &Client,
+    origin: &str,
+    page_code: &str,
+    endpoints: &[EndpointRoute],
+    api_base_url: &str,
+) -> Result<(), String> {
+    let is_used_by_page = |endpoint: &&EndpointRoute| page_code.contains(&endpoint.route_path().static_prefix());
+    let simple_endpoint = endpoints
+        .iter()
+        .filter(|endpoint| endpoint.method == HTTPMethod::Get && !endpoint.is_route_dynamic)
+        .find(is_used_by_page);
+
+    // Without a GET the page can send as is, check the preflight of a request it makes instead.
+    let (request, description) = if let Some(endpoint) = simple_endpoint {
+        (client.get(format!("{}{}", api_base_url, endpoint.route)), format!("GET {}", endpoint.route))
+    } else if let Some(endpoint) = endpoints.iter().find(is_used_by_page) {
+        let method = format!("{:?}", endpoint.method).to_uppercase();
+        let request = client
+            .request(reqwest::Method::OPTIONS, format!("{}{}", api_base_url, endpoint.route_path().template()))
+            .header("Access-Control-Request-Method", &method)
+            .header("Access-Control-Request-Headers", "content-type");
+        (request, format!("The preflight of {} {}", method, endpoint.route))
+    } else {
+        return Err("The page does not call any endpoint of the API.".to_string());
+    };
+
+    let response = request
+        .header("Origin", origin)
+        .send()
+        .await
+        .map_err(|error| format!("{} from the page failed: {}.", description, error))?;
+    let status_code = response.status().as_u16();
+    let is_expected_status_code = match simple_endpoint {
+        Some(endpoint) => endpoint.accepts_status_code(status_code),
+        None => response.status().is_success(),
+    };
+    if !is_expected_status_code {
+        return Err(format!("{} from the page responded with status code {}.", description, status_code));
+    }
+    let allowed_origin = response
+        .headers()
+        .get("Access-Control-Allow-Origin")
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    if allowed_origin != origin && allowed_origin != "*" {
+        return Err(format!("{} from the page is blocked by CORS, since the API does not allow origin `{}`.", description, origin));
+    }
+    Ok(())
+}
+
+// Serve the saved frontend, load each page and its local assets, check that the page only requests routes of the API,
+// and call the running API the way the page does, returning every problem found.
+pub async fn smoke_check_running_frontend(
+    frontend_directory: &Path,
+    endpoints: &[EndpointRoute],
+    api_base_url: &str,
+) -> Vec<String> {
+    let (frontend_port, frontend_server) = match serve_frontend_directory(frontend_directory.to_path_buf()).await {
+        Ok(served_frontend) => served_frontend,
+        Err(error) => return vec![format!("Unable to serve the frontend: {}.", error)],
+    };
+    let origin = format!("http://localhost:{}", frontend_port);
+    let client = Client::builder()
+        .timeout(FRONTEND_REQUEST_TIMEOUT)
+        .build()
+        .expect("Unable to instantiate reqwest client.");
+
+    let mut page_names = BTreeSet::from([FRONTEND_ENTRY_PAGE.to_string()]);
+    if let Ok(directory_entries) = std::fs::read_dir(frontend_directory) {
+        page_names.extend(
+            directory_entries
+                .filter_map(|directory_entry| directory_entry.ok()?.file_name().into_string().ok())
+                .filter(|file_name| file_name.ends_with(".html") && is_valid_frontend_file_name(file_name)),
+        );
+    }
+
+    let mut problems: Vec<String> = vec![];
+    for page_name in &page_names {
+        let page = match load_served_file(&client, &format!("{}/{}", origin, page_name)).await {
+            Ok(page) => page,
+            Err(problem) => {
+                problems.push(problem);
+                continue;
+            },
+        };
+        let mut page_code = page.clone();
+        let mut references = attribute_values(&page, "src");
+        references.extend(attribute_values(&page, "href"));
+        for reference in references.iter().filter(|reference| is_local_reference(reference)) {
+            match load_served_file(&client, &format!("{}/{}", origin, referenced_file_name(reference))).await {
+                Ok(contents) => page_code.push_str(&contents),
+                Err(problem) => problems.push(problem),
+            }
+        }
+
+        for path in api_call_paths(&page_code) {
+            if !endpoints.iter().any(|endpoint| is_route_path_called(&endpoint.route_path(), &path)) {
+                problems.push(format!("`{}` requests `{}`, which is not a route of the API.", page_name, path));
+            }
+        }
+        if let Err(problem) = call_api_from_page(&client, &origin, &page_code, endpoints, api_base_url).await {
+            problems.push(format!("`{}`: {}", page_name, problem));
+        }
+    }
+
+    frontend_server.abort();
+    problems
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::endpoint_route::HTTPMethod;
+
+    #[test]
+    fn test_smoke_check_frontend() {
+        let endpoints = vec![EndpointRoute::new(HTTPMethod::Get, "/task/{id}".to_string(), None, None)];
+        let mut files = BTreeMap::from([
+            (
+                "index.html".to_string(),
+                "<!DOCTYPE html><html><head><link rel=\"stylesheet\" href=\"styles.css\"></head>\
+                <body><a href=\"#top\">Top</a><script src=\"config.js\"></script><script src=\"./app.js\"></script></body></html>".to_string(),
+            ),
+            ("styles.css".to_string(), "body { margin: 0; }".to_string()),
+            ("config.js".to_string(), "window.API_BASE_URL = \"http://127.0.0.1:8080\";".to_string()),
+            ("app.js".to_string(), "fetch(`${window.API_BASE_URL}/task/${id}`);".to_string()),
+        ]);
+        assert_eq!(smoke_check_frontend(&files, &endpoints), Vec::<String>::new());
+
+        files.remove("styles.css");
+        files.insert("app.js".to_string(), "console.log('offline');".to_string());
+        files.insert("../escape.js".to_string(), String::new());
+        assert_eq!(smoke_check_frontend(&files, &endpoints), vec![
+            "`../escape.js` is not a plain .html, .css or .js file name.".to_string(),
+            "`index.html` references `styles.css`, which does not exist.".to_string(),
+            "`index.html` never calls the API with `fetch` on one of its routes.".to_string(),
+        ]);
+    }
+
+    #[test]
+    fn test_api_call_paths() {
+        let code = "fetch(`${window.API_BASE_URL}/task/${encodeURIComponent(task.id)}?full=1`); \
+            fetch(API_BASE_URL + '/task/' + id); const url = window.API_BASE_URL;";
+        assert_eq!(api_call_paths(code), vec!["/task/${encodeURIComponent(task.id)}", "/task/"]);
+
+        let route_path = RoutePath::parse("/task/{id}");
+        assert!(is_route_path_called(&route_path, "/task/${encodeURIComponent(task.id)}"));
+        assert!(is_route_path_called(&route_path, "/task/"));
+        assert!(!is_route_path_called(&route_path, "/task"));
+        assert!(!is_route_path_called(&route_path, "/tasks/${id}"));
+        assert!(is_route_path_called(&RoutePath::parse("/"), "/"));
+        assert!(is_route_path_called(&RoutePath::parse("/static/{tail:.*}"), "/static/css/app.css"));
+    }
+
+    // Answer every request like an API that allows the origin of the page.
+    async fn serve_fake_api(allowed_origin: &'static str) -> u16 {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.expect("Failed to bind fake API.");
+        let port = listener.local_addr().expect("Failed to get fake API address.").port();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request_head = vec![0; MAXIMUM_REQUEST_HEAD_LENGTH];
+                _ = stream.read(&mut request_head).await;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nAccess-Control-Allow-Origin: {}\r\nContent-Type: application/json\r\nContent-Length: 2\r\nConnection: close\r\n\r\n[]",
+                    allowed_origin,
+                );
+                _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        port
+    }
+
+    #[tokio::test]
+    async fn test_smoke_check_running_frontend() {
+        let frontend_directory = std::env::temp_dir().join(format!("frontend_smoke_check_{}", std::process::id()));
+        _ = std::fs::remove_dir_all(&frontend_directory);
+        std::fs::create_dir_all(&frontend_directory).expect("Failed to create frontend directory.");
+        for (file_name, contents) in [
+            ("index.html", "<html><body><script src=\"config.js\"></script><script src=\"app.js\"></script></body></html>"),
+            ("config.js", "window.API_BASE_URL = \"http://127.0.0.1\";"),
+            ("app.js", "fetch(`${window.API_BASE_URL}/task`);"),
+        ] {
+            std::fs::write(frontend_directory.join(file_name), contents).expect("Failed to write frontend file.");
+        }
+        let endpoints = vec![
+            EndpointRoute::new(HTTPMethod::Get, "/task".to_string(), None, None),
+            EndpointRoute::new(HTTPMethod::Delete, "/task/{id}".to_string(), None, None),
+        ];
+
+        let api_base_url = format!("http://127.0.0.1:{}", serve_fake_api("*").await);
+        assert_eq!(smoke_check_running_frontend(&frontend_directory, &endpoints, &api_base_url).await, Vec::<String>::new());
+
+        // An API which only allows another origin blocks the page, and so does a missing script.
+        let api_base_url = format!("http://127.0.0.1:{}", serve_fake_api("http://example.com").await);
+        std::fs::remove_file(frontend_directory.join("app.js")).expect("Failed to remove script.");
+        let problems = smoke_check_running_frontend(&frontend_directory, &endpoints, &api_base_url).await;
+        assert_eq!(problems.len(), 2);
+        assert!(problems[0].ends_with("/app.js` did not load: status code 404."));
+        assert_eq!(problems[1], "`index.html`: The page does not call any endpoint of the API.");
+
+        std::fs::write(frontend_directory.join("app.js"), "fetch(`${window.API_BASE_URL}/task`);").expect("Failed to write script.");
+        let problems = smoke_check_running_frontend(&frontend_directory, &endpoints, &api_base_url).await;
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].starts_with("`index.html`: GET /task from the page is blocked by CORS"));
+
+        // Every page is checked, including the routes its calls request.
+        let api_base_url = format!("http://127.0.0.1:{}", serve_fake_api("*").await);
+        std::fs::write(
+            frontend_directory.join("task.html"),
+            "<html><body><script src=\"config.js\"></script><script>\
+            fetch(window.API_BASE_URL + \"/task/\" + id, { method: \"DELETE\" }); fetch(`${window.API_BASE_URL}/tasks/${id}`);\
+            </script></body></html>",
+        ).expect("Failed to write page.");
+        let problems = smoke_check_running_frontend(&frontend_directory, &endpoints, &api_base_url).await;
+        assert_eq!(problems, vec!["`task.html` requests `/tasks/${id}`, which is not a route of the API.".to_string()]);
+        _ = std::fs::remove_dir_all(&frontend_directory);
+    }
+}
@@ -0,0 +1,365 @@
+/*
+ *  utilities/integration_test_generator.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::endpoint_testing::{ plan_endpoint_tests, EndpointTestStep, SYNTHESIZED_RESOURCE_ID };
+use super::code_naming::snake_case;
+use crate::model::endpoint_route::{ EndpointRoute, HTTPMethod };
+use crate::model::route_path::RouteSegment;
+
+use serde_json::Value;
+use std::fmt::Write;
+
+// Identifier for resources that are never created, used by the error cases.
+const MISSING_RESOURCE_ID: u64 = 987_654_321;
+
+// Support code shared by every generated test: launching the server binary and checking responses.
+// The binary is located through the `{package_name}` placeholder, which is replaced with the package name.
+const INTEGRATION_TEST_RUNTIME: &str = r####"// Generated by rust-auto-gpt-coder from the backend endpoint schema. Do not edit.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::net::{ TcpListener, TcpStream };
+use std::path::PathBuf;
+use std::process::{ Child, Command, Stdio };
+use std::time::{ Duration, Instant };
+
+const SERVER_HOST: &str = "127.0.0.1";
+const SERVER_STARTUP_DEADLINE: Duration = Duration::from_secs(30);
+
+// Server binary running in its own data directory, so tests do not share a database.
+struct TestServer {
+    child: Child,
+    base_url: String,
+    data_directory: PathBuf,
+}
+
+impl TestServer {
+    fn launch(test_name: &str) -> Self {
+        let port = TcpListener::bind((SERVER_HOST, 0))
+            .and_then(|listener| listener.local_addr())
+            .expect("Failed to find a free port.")
+            .port();
+        let data_directory = std::env::temp_dir().join(format!("backend_code_{}_{}", test_name, std::process::id()));
+        _ = std::fs::remove_dir_all(&data_directory);
+        std::fs::create_dir_all(&data_directory).expect("Failed to create test data directory.");
+
+        let mut child = Command::new(env!("CARGO_BIN_EXE_{package_name}"))
+            .current_dir(&data_directory)
+            .env("BACKEND_HOST", SERVER_HOST)
+            .env("BACKEND_PORT", port.to_string())
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .expect("Failed to launch backend server.");
+
+        let started_at = Instant::now();
+        while TcpStream::connect((SERVER_HOST, port)).is_err() {
+            if let Ok(Some(status)) = child.try_wait() {
+                panic!("Backend server exited during startup with {}.", status);
+            }
+            if started_at.elapsed() > SERVER_STARTUP_DEADLINE {
+                _ = child.kill();
+                panic!("Backend server did not accept connections within {:?}.", SERVER_STARTUP_DEADLINE);
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        Self {
+            child,
+            base_url: format!("http://{}:{}", SERVER_HOST, port),
+            data_directory,
+        }
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        _ = self.child.kill();
+        _ = self.child.wait();
+        _ = std::fs::remove_dir_all(&self.data_directory);
+    }
+}
+
+// Replace route parameters with the resource identifier, and wildcard tails with a sample path.
+fn fill_route(route: &str, resource_id: u64) -> String {
+    route.replace("{*}", "test").replace("{}", &resource_id.to_string())
+}
+
+async fn send(server: &TestServer, method: &str, path: &str, body: Option<&str>) -> (u16, String) {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .expect("Failed to build HTTP client.");
+    let method = reqwest::Method::from_bytes(method.as_bytes()).expect("Invalid HTTP method.");
+    let mut request = client.request(method, format!("{}{}", server.base_url, path));
+    if let Some(body) = body {
+        request = request
+            .header("Content-Type", "application/json")
+            .body(body.to_string());
+    }
+    let response = request.send().await.expect("Request to backend server failed.");
+    let status_code = response.status().as_u16();
+    (status_code, response.text().await.unwrap_or_default())
+}
+
+// Check a JSON value against a declared field type, where lists may be declared by their element type.
+fn matches_shape(shape: &Value, value: &Value) -> bool {
+    match shape {
+        Value::String(type_name) => match type_name.as_str() {
+            "integer" => value.is_i64() || value.is_u64(),
+            "number" => value.is_number(),
+            "string" => value.is_string(),
+            "bool" => value.is_boolean(),
+            _ => true,
+        },
+        _ => match shape["$type"].as_str() {
+            Some("nullable") => value.is_null() || matches_shape(&shape["inner"], value),
+            Some("array") => value
+                .as_array()
+                .is_some_and(|elements| elements.iter().all(|element| matches_shape(&shape["items"], element))),
+            Some("object") => match value {
+                Value::Array(elements) => elements.iter().all(|element| matches_shape(shape, element)),
+                Value::Object(fields) => shape["fields"].as_object().is_some_and(|field_shapes| field_shapes
+                    .iter()
+                    .all(|(name, field_shape)| match fields.get(name) {
+                        Some(field) => matches_shape(field_shape, field),
+                        None => field_shape["$type"] == "nullable",
+                    })),
+                _ => false,
+            },
+            _ => true,
+        },
+    }
+}
+
+// Check one step of a happy path, returning the identifier the server assigned, if any.
+fn check_response(
+    description: &str,
+    status_code: u16,
+    response_body: &str,
+    expected_status_codes: &[u16],
+    response_shape: Option<&str>,
+) -> Option<u64> {
+    // An endpoint which does not declare its status codes may respond with any success.
+    let is_expected_status_code = if expected_status_codes.is_empty() {
+        (200..300).contains(&status_code)
+    } else {
+        expected_status_codes.contains(&status_code)
+    };
+    assert!(
+        is_expected_status_code,
+        "{} responded with status code {} instead of {:?}. Response body: {}",
+        description, status_code, expected_status_codes, response_body,
+    );
+    let response_json = serde_json::from_str::<Value>(response_body).ok();
+    if let Some(response_shape) = response_shape {
+        let response_shape: Value = serde_json::from_str(response_shape).expect("Invalid response shape.");
+        let is_matching = response_json
+            .as_ref()
+            .is_some_and(|response_json| matches_shape(&response_shape, response_json));
+        assert!(
+            is_matching,
+            "{} returned a body that does not match the declared response {}. Response body: {}",
+            description, response_shape, response_body,
+        );
+    }
+    response_json.and_then(|response_json| response_json.get("id").and_then(Value::as_u64))
+}
+"####;
+
+// Quote a string as a Rust raw string literal.
+fn raw_string_literal(contents: &str) -> String {
+    let mut hashes = "#".to_string();
+    while contents.contains(&format!("\"{}", hashes)) {
+        hashes.push('#');
+    }
+    format!("r{}\"{}\"{}", hashes, contents, hashes)
+}
+
+fn optional_literal(contents: Option<String>) -> String {
+    match contents {
+        Some(contents) => format!("Some({})", raw_string_literal(&contents)),
+        None => "None".to_string(),
+    }
+}
+
+fn method_name(method: &HTTPMethod) -> String {
+    format!("{:?}", method).to_uppercase()
+}
+
+// Emit one test running every endpoint against a single server, each resource in create → read → update → delete order.
+fn write_happy_path_test(test_suite: &mut String, steps: &[EndpointTestStep]) {
+    test_suite.push_str(
+        "
+#[tokio::test]
+async fn happy_path() {
+    let server = TestServer::launch(\"happy_path\");
+    let mut resource_ids: HashMap<&str, u64> = HashMap::new();
+",
+    );
+    for step in steps {
+        let endpoint = &step.endpoint;
+        let request_body = step.request_body.as_ref().map(Value::to_string);
+        let response_shape = endpoint.response.as_ref().map(|response| response.to_value().to_string());
+        let status_codes: Vec<String> = endpoint.status_codes.iter().map(u16::to_string).collect();
+        _ = write!(
+            test_suite,
+            "
+    // {method} {route}
+    let resource_id = *resource_ids.get({resource:?}).unwrap_or(&{SYNTHESIZED_RESOURCE_ID});
+    let path = fill_route({normalized_route:?}, resource_id);
+    let (status_code, response_body) = send(&server, {method:?}, &path, {request_body}).await;
+    if let Some(created_id) = check_response(
+        &format!(\"{method} {{}}\", path),
+        status_code,
+        &response_body,
+        &[{status_codes}],
+        {response_shape},
+    ) {{
+        resource_ids.insert({resource:?}, created_id);
+    }}
+",
+            method = method_name(&endpoint.method),
+            route = endpoint.route,
+            resource = step.resource,
+            normalized_route = endpoint.route_path().normalized(),
+            request_body = optional_literal(request_body),
+            status_codes = status_codes.join(", "),
+            response_shape = optional_literal(response_shape),
+        );
+    }
+    test_suite.push_str("}\n");
+}
+
+// Emit a test expecting a client error when the request body is not valid JSON.
+fn write_malformed_body_test(test_suite: &mut String, endpoint: &EndpointRoute, operation_name: &str) {
+    let test_name = format!("{}_rejects_malformed_body", snake_case(operation_name));
+    _ = write!(
+        test_suite,
+        "
+#[tokio::test]
+async fn {test_name}() {{
+    let server = TestServer::launch({test_name:?});
+    let path = fill_route({normalized_route:?}, {SYNTHESIZED_RESOURCE_ID});
+    let (status_code, response_body) = send(&server, {method:?}, &path, Some(\"{{ not json\")).await;
+    assert!(
+        (400..500).contains(&status_code),
+        \"{method} {{}} accepted a malformed body with status code {{}}. Response body: {{}}\",
+        path, status_code, response_body,
+    );
+}}
+",
+        normalized_route = endpoint.route_path().normalized(),
+        method = method_name(&endpoint.method),
+    );
+}
+
+// Emit a test expecting a client error when reading a resource that was never created.
+fn write_missing_resource_test(test_suite: &mut String, endpoint: &EndpointRoute, operation_name: &str) {
+    let test_name = format!("{}_rejects_missing_resource", snake_case(operation_name));
+    _ = write!(
+        test_suite,
+        "
+#[tokio::test]
+async fn {test_name}() {{
+    let server = TestServer::launch({test_name:?});
+    let path = fill_route({normalized_route:?}, {MISSING_RESOURCE_ID});
+    let (status_code, response_body) = send(&server, \"GET\", &path, None).await;
+    assert!(
+        (400..500).contains(&status_code),
+        \"GET {{}} for a resource that does not exist responded with status code {{}}. Response body: {{}}\",
+        path, status_code, response_body,
+    );
+}}
+",
+        normalized_route = endpoint.route_path().normalized(),
+    );
+}
+
+// Generate an integration test suite for the `tests/` directory of the generated project, whose binary is named after `package_name`.
+pub fn integration_test_suite(endpoints: &[EndpointRoute], package_name: &str) -> String {
+    let mut test_suite = INTEGRATION_TEST_RUNTIME.replace("{package_name}", package_name);
+
+    // Order endpoints by route and method, so the committed suite only changes when the endpoints do.
+    let mut endpoints = endpoints.to_vec();
+    endpoints.sort_by_key(|endpoint| (endpoint.route_path().normalized(), method_name(&endpoint.method)));
+    let endpoints = endpoints.as_slice();
+
+    write_happy_path_test(&mut test_suite, &plan_endpoint_tests(endpoints));
+
+    for (endpoint, operation_name) in endpoints.iter().zip(EndpointRoute::operation_names(endpoints)) {
+        if endpoint.request_body.is_some() {
+            write_malformed_body_test(&mut test_suite, endpoint, &operation_name);
+        }
+        let has_parameter = endpoint
+            .route_path()
+            .segments
+            .iter()
+            .any(|segment| matches!(segment, RouteSegment::Parameter(_)));
+        if endpoint.method == HTTPMethod::Get && has_parameter {
+            write_missing_resource_test(&mut test_suite, endpoint, &operation_name);
+        }
+    }
+
+    test_suite.push_str(
+        "
+#[tokio::test]
+async fn unknown_route_is_not_found() {
+    let server = TestServer::launch(\"unknown_route_is_not_found\");
+    let (status_code, _) = send(&server, \"GET\", \"/this-route-does-not-exist/0\", None).await;
+    assert_eq!(status_code, 404);
+}
+",
+    );
+    test_suite
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utilities::backend_code_persistence::BackendCodePersistence;
+    use crate::utilities::rest_api_analyzer::analyze_rest_api_endpoints;
+
+    #[test]
+    fn test_integration_test_suite_for_code_template() {
+        let code_template = BackendCodePersistence::read_code_template_contents();
+        let endpoints = analyze_rest_api_endpoints(&code_template)
+            .expect("Failed to parse code template.")
+            .endpoints;
+
+        let test_suite = integration_test_suite(&endpoints, "backend_code");
+        syn::parse_file(&test_suite).expect("Generated test suite is not valid Rust.");
+        assert!(test_suite.contains("async fn happy_path() {"));
+        assert!(test_suite.contains("async fn post_task_rejects_malformed_body() {"));
+        assert!(test_suite.contains("async fn get_task_id_rejects_missing_resource() {"));
+        assert!(test_suite.contains("let resource_id = *resource_ids.get(\"/task\").unwrap_or(&4242);"));
+        assert!(test_suite.contains("env!(\"CARGO_BIN_EXE_backend_code\")"));
+        assert_eq!(raw_string_literal("{\"a\":\"#\"}"), "r##\"{\"a\":\"#\"}\"##");
+
+        // Registering the same endpoints in another order generates the same suite.
+        let reversed_endpoints: Vec<EndpointRoute> = endpoints.iter().rev().cloned().collect();
+        assert_eq!(integration_test_suite(&reversed_endpoints, "backend_code"), test_suite);
+    }
+
+    #[test]
+    fn test_integration_test_suite_for_renamed_package() {
+        let code_template = BackendCodePersistence::read_code_template_contents();
+        let endpoints = analyze_rest_api_endpoints(&code_template)
+            .expect("Failed to parse code template.")
+            .endpoints;
+
+        let test_suite = integration_test_suite(&endpoints, "todo-api");
+        syn::parse_file(&test_suite).expect("Generated test suite is not valid Rust.");
+        assert!(test_suite.contains("env!(\"CARGO_BIN_EXE_todo-api\")"));
+        assert!(!test_suite.contains("CARGO_BIN_EXE_backend_code"));
+    }
+}
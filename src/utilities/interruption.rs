@@ -0,0 +1,92 @@
+/*
+ *  utilities/interruption.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use std::sync::atomic::{ AtomicBool, Ordering };
+use tokio::sync::Notify;
+
+pub const INTERRUPTED_EXIT_CODE: u8 = 130;
+
+// Interruption of the program by the user, set by the Ctrl-C handler.
+static SHARED_INTERRUPTION: Interruption = Interruption::new();
+
+// A flag that work waits on, so it can stop and save its state when the user interrupts it.
+#[derive(Debug)]
+pub struct Interruption {
+    is_interrupted: AtomicBool,
+    notify: Notify,
+}
+
+impl Interruption {
+    pub const fn new() -> Self {
+        Self {
+            is_interrupted: AtomicBool::new(false),
+            notify: Notify::const_new(),
+        }
+    }
+
+    // The interruption set when the user presses Ctrl-C.
+    pub fn shared() -> &'static Interruption {
+        &SHARED_INTERRUPTION
+    }
+
+    pub fn interrupt(&self) {
+        self.is_interrupted.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    pub fn is_interrupted(&self) -> bool {
+        self.is_interrupted.load(Ordering::SeqCst)
+    }
+
+    // Wait until interrupted, returning right away when it already was.
+    pub async fn wait(&self) {
+        loop {
+            let notified = self.notify.notified();
+            if self.is_interrupted() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+impl Default for Interruption {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_interruption() {
+        static INTERRUPTION: Interruption = Interruption::new();
+        assert!(!INTERRUPTION.is_interrupted());
+
+        let waiter = tokio::spawn(INTERRUPTION.wait());
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiter.is_finished());
+        INTERRUPTION.interrupt();
+        tokio::time::timeout(Duration::from_secs(1), waiter)
+            .await
+            .expect("Waiting did not end when interrupted.")
+            .expect("Waiting task failed.");
+
+        // Work that starts after the interruption stops right away.
+        assert!(INTERRUPTION.is_interrupted());
+        tokio::time::timeout(Duration::from_secs(1), INTERRUPTION.wait())
+            .await
+            .expect("Waiting did not end after the interruption.");
+    }
+}
@@ -0,0 +1,164 @@
+/*
+ *  utilities/logging.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::event_bus::{ AgentEvent, EventSink };
+
+use std::env;
+use std::fs::{ self, OpenOptions };
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tracing_subscriber::EnvFilter;
+
+const LOG_FILTER_ENVIRONMENT_VARIABLE: &str = "RUST_AUTO_GPT_LOG";
+const LOG_FORMAT_ENVIRONMENT_VARIABLE: &str = "RUST_AUTO_GPT_LOG_FORMAT";
+const LOG_FILE_ENVIRONMENT_VARIABLE: &str = "RUST_AUTO_GPT_LOG_FILE";
+
+// Keep the terminal for agents' own messages unless more is asked for.
+const DEFAULT_LOG_FILTER: &str = "warn";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl LogFormat {
+    pub fn parse(format: &str) -> Result<Self, String> {
+        match format.to_lowercase().as_str() {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("Unknown log format \"{}\"; expected \"text\" or \"json\".", format)),
+        }
+    }
+}
+
+// How much is logged, in which format, and where to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoggingConfiguration {
+    pub filter: String,
+    pub format: LogFormat,
+    pub file: Option<PathBuf>,
+}
+
+impl Default for LoggingConfiguration {
+    fn default() -> Self {
+        Self {
+            filter: DEFAULT_LOG_FILTER.to_string(),
+            format: LogFormat::Text,
+            file: None,
+        }
+    }
+}
+
+impl LoggingConfiguration {
+    // Read the configuration from environment variables, such as `RUST_AUTO_GPT_LOG=debug`.
+    pub fn from_environment() -> Result<Self, String> {
+        let mut configuration = Self::default();
+        if let Ok(filter) = env::var(LOG_FILTER_ENVIRONMENT_VARIABLE) {
+            configuration.filter = filter;
+        }
+        if let Ok(format) = env::var(LOG_FORMAT_ENVIRONMENT_VARIABLE) {
+            configuration.format = LogFormat::parse(&format)?;
+        }
+        if let Ok(file) = env::var(LOG_FILE_ENVIRONMENT_VARIABLE) {
+            configuration.file = Some(PathBuf::from(file));
+        }
+        Ok(configuration)
+    }
+
+    // Override the filter from `-v` and `-q` flags: each `-v` logs more, down to traces, and `-q` only logs errors.
+    pub fn apply_verbosity(&mut self, verbose_count: u8, is_quiet: bool) {
+        let filter = match (is_quiet, verbose_count) {
+            (true, _) => "error",
+            (false, 0) => return,
+            (false, 1) => "info",
+            (false, 2) => "debug",
+            (false, _) => "trace",
+        };
+        self.filter = filter.to_string();
+    }
+
+    // Install the global subscriber; logs go to standard error unless a file is configured.
+    pub fn initialize(&self) -> Result<(), String> {
+        let filter = EnvFilter::try_new(&self.filter)
+            .map_err(|error| format!("Invalid log filter \"{}\": {}", self.filter, error))?;
+        let builder = tracing_subscriber::fmt()
+            .with_env_filter(filter);
+
+        let result = match (&self.file, self.format) {
+            (Some(path), format) => {
+                if let Some(directory) = path.parent() {
+                    fs::create_dir_all(directory)
+                        .map_err(|error| format!("Failed to create log directory {}: {}", directory.display(), error))?;
+                }
+                let file = OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .map_err(|error| format!("Failed to open log file {}: {}", path.display(), error))?;
+                let builder = builder
+                    .with_ansi(false)
+                    .with_writer(Mutex::new(file));
+                match format {
+                    LogFormat::Text => builder.try_init(),
+                    LogFormat::Json => builder.json().with_current_span(true).with_span_list(true).try_init(),
+                }
+            },
+            (None, LogFormat::Text) => builder.with_writer(std::io::stderr).try_init(),
+            (None, LogFormat::Json) => builder
+                .with_writer(std::io::stderr)
+                .json()
+                .with_current_span(true)
+                .with_span_list(true)
+                .try_init(),
+        };
+        result.map_err(|error| format!("Failed to initialize logging: {}", error))
+    }
+}
+
+// Records every agent event in the log, inside the span of the agent or call that published it.
+pub struct TracingEventSink {}
+
+impl EventSink for TracingEventSink {
+    fn handle_event(&self, event: &AgentEvent) {
+        let Ok(event_json) = serde_json::to_string(event) else {
+            return;
+        };
+        match event {
+            AgentEvent::StatusChanged { .. } | AgentEvent::LlmCallStarted { .. } => {
+                tracing::debug!(target: "agent_event", event = %event_json);
+            },
+            _ => {
+                tracing::info!(target: "agent_event", event = %event_json);
+            },
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_format() {
+        assert_eq!(LogFormat::parse("JSON"), Ok(LogFormat::Json));
+        assert_eq!(LogFormat::parse("text"), Ok(LogFormat::Text));
+        assert!(LogFormat::parse("xml").is_err());
+
+        let mut configuration = LoggingConfiguration::default();
+        configuration.apply_verbosity(0, false);
+        assert_eq!(configuration.filter, DEFAULT_LOG_FILTER);
+        configuration.apply_verbosity(2, false);
+        assert_eq!(configuration.filter, "debug");
+        configuration.apply_verbosity(2, true);
+        assert_eq!(configuration.filter, "error");
+    }
+}
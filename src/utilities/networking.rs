@@ -7,7 +7,18 @@
  *
  */
 
-use reqwest::Client;
+use crate::model::endpoint_route::HTTPMethod;
+
+use reqwest::{ Client, Method };
+use serde_json::Value;
+use std::net::TcpListener;
+
+// Environment variables honored by the generated backend server for its bind address.
+pub const BACKEND_HOST_ENVIRONMENT_VARIABLE: &str = "BACKEND_HOST";
+pub const BACKEND_PORT_ENVIRONMENT_VARIABLE: &str = "BACKEND_PORT";
+
+// Port the generated backend server listens on when `BACKEND_PORT` is not set.
+pub const DEFAULT_BACKEND_PORT: u16 = 8080;
 
 // Check whether request URL is valid.
 pub async fn check_status_code(client: &Client, url: &str) -> Result<u16, reqwest::Error> {
@@ -17,3 +28,61 @@ pub async fn check_status_code(client: &Client, url: &str) -> Result<u16, reqwes
         .await?;
     Ok(response.status().as_u16())
 }
+
+// Send a request with an optional JSON body, returning the status code and response body.
+pub async fn send_request(
+    client: &Client, 
+    method: &HTTPMethod, 
+    url: &str, 
+    body: Option<&Value>, 
+) -> Result<(u16, String), reqwest::Error> {
+    let method = match method {
+        HTTPMethod::Get     => Method::GET,
+        HTTPMethod::Patch   => Method::PATCH,
+        HTTPMethod::Post    => Method::POST,
+        HTTPMethod::Put     => Method::PUT,
+        HTTPMethod::Delete  => Method::DELETE,
+        HTTPMethod::Head    => Method::HEAD,
+        HTTPMethod::Options => Method::OPTIONS,
+    };
+    let mut request = client.request(method, url);
+    if let Some(body) = body {
+        request = request.json(body);
+    }
+    let response = request.send().await?;
+    let status_code = response.status().as_u16();
+    let response_body = response.text().await?;
+    Ok((status_code, response_body))
+}
+
+
+// Check whether nothing is currently listening on the given address.
+pub fn is_port_available(host: &str, port: u16) -> bool {
+    TcpListener::bind((host, port)).is_ok()
+}
+
+// Ask the operating system for a port that is currently free on the given host.
+pub fn find_available_port(host: &str) -> std::io::Result<u16> {
+    let listener = TcpListener::bind((host, 0))?;
+    Ok(listener.local_addr()?.port())
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_available_port() {
+        let port = find_available_port("127.0.0.1")
+            .expect("Failed to find an available port.");
+        assert_ne!(port, 0);
+        assert!(is_port_available("127.0.0.1", port));
+
+        // Occupy the port and confirm the collision is detected.
+        let _listener = TcpListener::bind(("127.0.0.1", port))
+            .expect("Failed to bind test listener.");
+        assert!(!is_port_available("127.0.0.1", port));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,269 @@
+/*
+ *  utilities/open_api.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use crate::model::endpoint_route::{ EndpointRoute, HTTPMethod };
+use crate::model::field_type::FieldType;
+use crate::model::route_path::RoutePath;
+
+use serde_json::{ json, Map, Value };
+use std::collections::HashSet;
+
+const OPEN_API_VERSION: &str = "3.1.0";
+const API_TITLE: &str = "backend_code";
+const API_VERSION: &str = "0.1.0";
+const SCHEMA_REFERENCE_PREFIX: &str = "#/components/schemas/";
+
+fn method_key(method: &HTTPMethod) -> &'static str {
+    match method {
+        HTTPMethod::Get     => "get",
+        HTTPMethod::Patch   => "patch",
+        HTTPMethod::Post    => "post",
+        HTTPMethod::Put     => "put",
+        HTTPMethod::Delete  => "delete",
+        HTTPMethod::Head    => "head",
+        HTTPMethod::Options => "options",
+    }
+}
+
+// Convert a field type into a JSON Schema.
+pub fn json_schema_for_field_type(field_type: &FieldType) -> Value {
+    match field_type {
+        FieldType::Integer => json!({ "type": "integer" }),
+        FieldType::Number => json!({ "type": "number" }),
+        FieldType::String => json!({ "type": "string" }),
+        FieldType::Bool => json!({ "type": "boolean" }),
+        FieldType::Any => json!({}),
+        FieldType::Array(element_type) => json!({ "type": "array", "items": json_schema_for_field_type(element_type) }),
+        FieldType::Object(field_types) => {
+            let properties: Map<String, Value> = field_types
+                .iter()
+                .map(|(name, field_type)| (name.clone(), json_schema_for_field_type(field_type)))
+                .collect();
+            let required: Vec<&String> = field_types
+                .iter()
+                .filter(|(_, field_type)| !matches!(field_type, FieldType::Nullable(_)))
+                .map(|(name, _)| name)
+                .collect();
+            json!({ "type": "object", "properties": properties, "required": required })
+        },
+        FieldType::Nullable(inner_type) => {
+            json!({ "anyOf": [json_schema_for_field_type(inner_type), { "type": "null" }] })
+        },
+    }
+}
+
+fn schema_reference(name: &str) -> Value {
+    json!({ "$ref": format!("{}{}", SCHEMA_REFERENCE_PREFIX, name) })
+}
+
+fn json_content(schema: Value) -> Value {
+    json!({ "application/json": { "schema": schema } })
+}
+
+// Convert the endpoint schema into an OpenAPI 3.1 document.
+pub fn open_api_document(description: &str, endpoints: &[EndpointRoute]) -> Value {
+    let mut paths: Map<String, Value> = Map::new();
+    let mut schemas: Map<String, Value> = Map::new();
+
+    for (endpoint, name) in endpoints.iter().zip(EndpointRoute::operation_names(endpoints)) {
+        let mut operation: Map<String, Value> = Map::new();
+        operation.insert("operationId".to_string(), Value::String(name.clone()));
+
+        let parameters: Vec<Value> = endpoint
+            .path_parameters
+            .iter()
+            .map(|parameter_name| json!({
+                "name": parameter_name,
+                "in": "path",
+                "required": true,
+                "schema": match endpoint.path_parameter_types.get(parameter_name) {
+                    Some(parameter_type) => json_schema_for_field_type(parameter_type),
+                    None => json!({ "type": "string" }),
+                },
+            }))
+            .collect();
+        if !parameters.is_empty() {
+            operation.insert("parameters".to_string(), Value::Array(parameters));
+        }
+
+        if let Some(request_body) = &endpoint.request_body {
+            let schema_name = format!("{}Request", name);
+            schemas.insert(schema_name.clone(), json_schema_for_field_type(request_body));
+            operation.insert("requestBody".to_string(), json!({
+                "required": true,
+                "content": json_content(schema_reference(&schema_name)),
+            }));
+        }
+
+        let mut success_response = json!({ "description": "Successful response" });
+        if let Some(response) = &endpoint.response {
+            let schema_name = format!("{}Response", name);
+            schemas.insert(schema_name.clone(), json_schema_for_field_type(response));
+            success_response["content"] = json_content(schema_reference(&schema_name));
+        }
+        let mut responses: Map<String, Value> = endpoint
+            .status_codes
+            .iter()
+            .map(|status_code| (status_code.to_string(), success_response.clone()))
+            .collect();
+        if responses.is_empty() {
+            responses.insert("2XX".to_string(), success_response);
+        }
+        operation.insert("responses".to_string(), Value::Object(responses));
+
+        let path_item = paths
+            .entry(endpoint.route_path().template())
+            .or_insert_with(|| Value::Object(Map::new()));
+        path_item[method_key(&endpoint.method)] = Value::Object(operation);
+    }
+
+    json!({
+        "openapi": OPEN_API_VERSION,
+        "info": {
+            "title": API_TITLE,
+            "description": description,
+            "version": API_VERSION,
+        },
+        "paths": paths,
+        "components": { "schemas": schemas },
+    })
+}
+
+// Collect every `$ref` target in a document.
+fn collect_references(value: &Value, references: &mut Vec<String>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                match (key.as_str(), field) {
+                    ("$ref", Value::String(reference)) => references.push(reference.clone()),
+                    _ => collect_references(field, references),
+                }
+            }
+        },
+        Value::Array(elements) => elements
+            .iter()
+            .for_each(|element| collect_references(element, references)),
+        _ => {},
+    }
+}
+
+// Check the structural rules of an OpenAPI 3.1 document, returning every violation found.
+pub fn validate_open_api_document(document: &Value) -> Vec<String> {
+    let mut errors: Vec<String> = vec![];
+
+    if document["openapi"].as_str().is_none_or(|version| !version.starts_with("3.1")) {
+        errors.push("`openapi` must be a 3.1.x version string.".to_string());
+    }
+    for field in ["title", "version"] {
+        if !document["info"][field].is_string() {
+            errors.push(format!("`info.{}` is required.", field));
+        }
+    }
+
+    let Some(paths) = document["paths"].as_object() else {
+        errors.push("`paths` must be an object.".to_string());
+        return errors;
+    };
+    let mut operation_ids: HashSet<String> = HashSet::new();
+    for (route, path_item) in paths {
+        if !route.starts_with('/') {
+            errors.push(format!("Path `{}` must start with `/`.", route));
+        }
+        let template_parameters: HashSet<String> = RoutePath::parse(route).parameter_names().into_iter().collect();
+        let Some(operations) = path_item.as_object() else {
+            errors.push(format!("Path item `{}` must be an object.", route));
+            continue;
+        };
+        for (method, operation) in operations {
+            let location = format!("{} {}", method.to_uppercase(), route);
+            if let Some(operation_id) = operation["operationId"].as_str()
+                && !operation_ids.insert(operation_id.to_string())
+            {
+                errors.push(format!("{}: duplicate operationId `{}`.", location, operation_id));
+            }
+            if operation["responses"].as_object().is_none_or(|responses| responses.is_empty()) {
+                errors.push(format!("{}: at least one response is required.", location));
+            }
+
+            // Path template parameters and declared path parameters must match exactly.
+            let declared_parameters: HashSet<String> = operation["parameters"]
+                .as_array()
+                .map(|parameters| parameters
+                    .iter()
+                    .filter(|parameter| parameter["in"] == "path")
+                    .filter_map(|parameter| parameter["name"].as_str().map(String::from))
+                    .collect())
+                .unwrap_or_default();
+            for missing_parameter in template_parameters.difference(&declared_parameters) {
+                errors.push(format!("{}: path parameter `{}` is not declared.", location, missing_parameter));
+            }
+            for extra_parameter in declared_parameters.difference(&template_parameters) {
+                errors.push(format!("{}: parameter `{}` is not in the path.", location, extra_parameter));
+            }
+        }
+    }
+
+    let mut references: Vec<String> = vec![];
+    collect_references(document, &mut references);
+    for reference in references {
+        let is_resolved = reference
+            .strip_prefix(SCHEMA_REFERENCE_PREFIX)
+            .is_some_and(|schema_name| document["components"]["schemas"].get(schema_name).is_some());
+        if !is_resolved {
+            errors.push(format!("Reference `{}` does not resolve.", reference));
+        }
+    }
+
+    errors
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utilities::backend_code_persistence::BackendCodePersistence;
+    use crate::utilities::rest_api_analyzer::analyze_rest_api_endpoints;
+
+    #[test]
+    fn test_open_api_document_for_code_template() {
+        let code_template = BackendCodePersistence::read_code_template_contents();
+        let endpoints = analyze_rest_api_endpoints(&code_template)
+            .expect("Failed to parse code template.")
+            .endpoints;
+
+        let document = open_api_document("build a website that tracks tasks", &endpoints);
+        assert_eq!(validate_open_api_document(&document), Vec::<String>::new());
+
+        let read_task = &document["paths"]["/task/{id}"]["get"];
+        assert_eq!(read_task["operationId"], "GetTaskId");
+        assert_eq!(read_task["parameters"][0]["name"], "id");
+        assert_eq!(read_task["parameters"][0]["schema"]["type"], "integer");
+        assert_eq!(read_task["responses"]["200"]["content"]["application/json"]["schema"]["$ref"], "#/components/schemas/GetTaskIdResponse");
+
+        let create_task_schema = &document["components"]["schemas"]["PostTaskRequest"];
+        assert_eq!(create_task_schema["properties"]["completed"]["type"], "boolean");
+    }
+
+    #[test]
+    fn test_validate_open_api_document_reports_errors() {
+        let document = json!({
+            "openapi": "3.0.0",
+            "info": { "title": "test" },
+            "paths": {
+                "/task/{id}": {
+                    "get": {
+                        "responses": { "200": { "description": "ok", "content": json_content(schema_reference("Missing")) } },
+                    },
+                },
+            },
+        });
+        assert_eq!(validate_open_api_document(&document).len(), 4);
+    }
+}
@@ -0,0 +1,817 @@
+/*
+ *  utilities/rest_api_analyzer.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use crate::model::endpoint_route::{ EndpointRoute, HTTPMethod };
+use crate::model::field_type::FieldType;
+
+use std::collections::{ BTreeMap, HashMap };
+use syn::visit::{ self, Visit };
+use syn::punctuated::Punctuated;
+use syn::{
+    Expr, ExprCall, ExprLet, ExprMatch, ExprMethodCall, Fields, FnArg, GenericArgument, ImplItemFn,
+    Item, ItemFn, ItemStruct, Lit, Local, Member, Pat, PathArguments, ReturnType, Signature, Token, Type,
+};
+
+// Limit for nested struct expansion, guarding against recursive types.
+const MAXIMUM_TYPE_DEPTH: usize = 8;
+
+// Success statuses by `HttpResponse` constructor and `StatusCode` constant.
+const SUCCESS_STATUS_CODES: &[(&str, &str, u16)] = &[
+    ("Ok", "OK", 200),
+    ("Created", "CREATED", 201),
+    ("Accepted", "ACCEPTED", 202),
+    ("NonAuthoritativeInformation", "NON_AUTHORITATIVE_INFORMATION", 203),
+    ("NoContent", "NO_CONTENT", 204),
+    ("ResetContent", "RESET_CONTENT", 205),
+    ("PartialContent", "PARTIAL_CONTENT", 206),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestApiAnalysis {
+    pub endpoints: Vec<EndpointRoute>,
+    pub unresolved: Vec<String>,
+}
+
+impl RestApiAnalysis {
+    // Whether the analyzer understood every route registration in the code.
+    pub fn is_complete(&self) -> bool {
+        !self.endpoints.is_empty() && self.unresolved.is_empty()
+    }
+}
+
+// A route registration found in the `App` builder chain, not yet matched with its handler.
+#[derive(Debug, Clone)]
+struct RouteRegistration {
+    route: String,
+    method: HTTPMethod,
+    handler: String,
+}
+
+#[derive(Default)]
+struct RouteCollector<'ast> {
+    structs: HashMap<String, &'ast ItemStruct>,
+    handlers: HashMap<String, &'ast ItemFn>,
+    functions: HashMap<String, &'ast Signature>,
+    registrations: Vec<RouteRegistration>,
+    unresolved: Vec<String>,
+    // Prefixes of the `web::scope(..)` calls enclosing the expression being visited.
+    scope_prefixes: Vec<String>,
+}
+
+fn http_method_for_name(name: &str) -> Option<HTTPMethod> {
+    match name {
+        "get" => Some(HTTPMethod::Get),
+        "patch" => Some(HTTPMethod::Patch),
+        "post" => Some(HTTPMethod::Post),
+        "put" => Some(HTTPMethod::Put),
+        "delete" => Some(HTTPMethod::Delete),
+        "head" => Some(HTTPMethod::Head),
+        "options" => Some(HTTPMethod::Options),
+        _ => None,
+    }
+}
+
+fn string_literal(expression: &Expr) -> Option<String> {
+    match expression {
+        Expr::Lit(expression_literal) => match &expression_literal.lit {
+            Lit::Str(string_literal) => Some(string_literal.value()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn last_path_segment(expression: &Expr) -> Option<String> {
+    match expression {
+        Expr::Path(expression_path) => expression_path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
+// Join a scope prefix and a route path without doubling slashes.
+fn join_route(prefix: &str, route: &str) -> String {
+    let joined = format!("{}/{}", prefix.trim_end_matches('/'), route.trim_start_matches('/'));
+    if joined.len() > 1 { joined.trim_end_matches('/').to_string() } else { joined }
+}
+
+impl<'ast> RouteCollector<'ast> {
+    // Resolve `web::get().to(handler)` into its method and handler name.
+    fn method_and_handler(&self, expression: &Expr) -> Option<(HTTPMethod, String)> {
+        let Expr::MethodCall(to_call) = expression else { return None };
+        if to_call.method != "to" || to_call.args.len() != 1 {
+            return None;
+        }
+        let handler = last_path_segment(&to_call.args[0])?;
+        let Expr::Call(method_call) = to_call.receiver.as_ref() else { return None };
+        let method_name = last_path_segment(&method_call.func)?;
+        Some((http_method_for_name(&method_name)?, handler))
+    }
+
+    // Walk back through a builder chain to find `web::scope(..)` or `web::resource(..)` paths.
+    fn chain_path(expression: &Expr) -> (String, Option<String>) {
+        let mut scope_prefix = String::new();
+        let mut resource_path: Option<String> = None;
+        let mut current = expression;
+        loop {
+            match current {
+                Expr::MethodCall(method_call) => current = method_call.receiver.as_ref(),
+                Expr::Call(ExprCall { func, args, .. }) => {
+                    match (last_path_segment(func).as_deref(), args.first().and_then(string_literal)) {
+                        (Some("scope"), Some(path)) => scope_prefix = join_route(&path, &scope_prefix),
+                        (Some("resource"), Some(path)) => resource_path = Some(path),
+                        _ => {},
+                    }
+                    return (scope_prefix, resource_path);
+                },
+                _ => return (scope_prefix, resource_path),
+            }
+        }
+    }
+
+    fn collect_route_call(&mut self, method_call: &ExprMethodCall) {
+        let (scope_prefix, resource_path) = Self::chain_path(method_call.receiver.as_ref());
+        let (route, route_expression) = match (method_call.args.len(), &resource_path) {
+            (2, _) => match string_literal(&method_call.args[0]) {
+                Some(route) => (route, &method_call.args[1]),
+                None => {
+                    self.unresolved.push("A `.route(..)` call uses a non-literal path.".to_string());
+                    return;
+                },
+            },
+            (1, Some(resource_path)) => (resource_path.clone(), &method_call.args[0]),
+            _ => return,
+        };
+
+        let enclosing_prefix = self.scope_prefixes
+            .iter()
+            .fold(String::new(), |prefix, scope_prefix| join_route(&prefix, scope_prefix));
+        match self.method_and_handler(route_expression) {
+            Some((method, handler)) => self.registrations.push(RouteRegistration {
+                route: join_route(&join_route(&enclosing_prefix, &scope_prefix), &route),
+                method,
+                handler,
+            }),
+            None => self.unresolved.push(format!("Unable to resolve the method or handler for route `{}`.", route)),
+        }
+    }
+
+    // Collect handlers registered through attribute macros such as `#[get("/path")]`.
+    fn collect_attribute_route(&mut self, item_fn: &ItemFn) {
+        for attribute in &item_fn.attrs {
+            let Some(method_name) = attribute.path().segments.last().map(|segment| segment.ident.to_string()) else {
+                continue;
+            };
+            let Some(method) = http_method_for_name(&method_name) else { continue };
+            match attribute.parse_args::<syn::LitStr>() {
+                Ok(route) => self.registrations.push(RouteRegistration {
+                    route: route.value(),
+                    method,
+                    handler: item_fn.sig.ident.to_string(),
+                }),
+                Err(_) => self.unresolved.push(format!(
+                    "Unable to read the route of the `#[{}]` attribute on `{}`.",
+                    method_name, item_fn.sig.ident,
+                )),
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for RouteCollector<'ast> {
+    fn visit_item_struct(&mut self, item_struct: &'ast ItemStruct) {
+        self.structs.insert(item_struct.ident.to_string(), item_struct);
+        visit::visit_item_struct(self, item_struct);
+    }
+
+    fn visit_item_fn(&mut self, item_fn: &'ast ItemFn) {
+        self.handlers.insert(item_fn.sig.ident.to_string(), item_fn);
+        self.functions.insert(item_fn.sig.ident.to_string(), &item_fn.sig);
+        self.collect_attribute_route(item_fn);
+        visit::visit_item_fn(self, item_fn);
+    }
+
+    fn visit_impl_item_fn(&mut self, impl_item_fn: &'ast ImplItemFn) {
+        self.functions.insert(impl_item_fn.sig.ident.to_string(), &impl_item_fn.sig);
+        visit::visit_impl_item_fn(self, impl_item_fn);
+    }
+
+    fn visit_expr_method_call(&mut self, method_call: &'ast ExprMethodCall) {
+        // Visit the receiver first, so routes are collected in registration order.
+        self.visit_expr(&method_call.receiver);
+
+        // Services added to a scope inherit its prefix, on top of the enclosing ones.
+        let (scope_prefix, _) = Self::chain_path(&method_call.receiver);
+        let is_scoped_service = method_call.method == "service" && !scope_prefix.is_empty();
+        if is_scoped_service {
+            self.scope_prefixes.push(scope_prefix);
+        }
+        for argument in &method_call.args {
+            self.visit_expr(argument);
+        }
+        if is_scoped_service {
+            self.scope_prefixes.pop();
+        }
+
+        if method_call.method == "route" {
+            self.collect_route_call(method_call);
+        }
+    }
+}
+
+// Get the last segment of a type path along with its generic arguments.
+fn type_path_segment(rust_type: &Type) -> Option<(String, Vec<&Type>)> {
+    let Type::Path(type_path) = rust_type else { return None };
+    let segment = type_path.path.segments.last()?;
+    let generic_types = match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => arguments
+            .args
+            .iter()
+            .filter_map(|argument| match argument {
+                GenericArgument::Type(generic_type) => Some(generic_type),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+    Some((segment.ident.to_string(), generic_types))
+}
+
+// Describe a Rust type as an endpoint schema field type.
+fn field_type(rust_type: &Type, structs: &HashMap<String, &ItemStruct>, depth: usize) -> FieldType {
+    if depth > MAXIMUM_TYPE_DEPTH {
+        return FieldType::Any;
+    }
+    match rust_type {
+        Type::Reference(reference) => return field_type(&reference.elem, structs, depth),
+        Type::Slice(slice) => return FieldType::Array(Box::new(field_type(&slice.elem, structs, depth + 1))),
+        Type::Array(array) => return FieldType::Array(Box::new(field_type(&array.elem, structs, depth + 1))),
+        _ => {},
+    }
+
+    let Some((name, generic_types)) = type_path_segment(rust_type) else {
+        return FieldType::Any;
+    };
+    let first_generic_type = |depth: usize| match generic_types.first() {
+        Some(inner_type) => field_type(inner_type, structs, depth),
+        None => FieldType::Any,
+    };
+    match name.as_str() {
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+            | "isize" => FieldType::Integer,
+        "f32" | "f64" => FieldType::Number,
+        "String" | "str" | "char" => FieldType::String,
+        "bool" => FieldType::Bool,
+        "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => FieldType::Array(Box::new(first_generic_type(depth + 1))),
+        "Option" => FieldType::Nullable(Box::new(first_generic_type(depth))),
+        "Box" | "Arc" | "Rc" | "Json" | "Path" | "Query" | "Form" | "Data" => first_generic_type(depth),
+        "HashMap" | "BTreeMap" => FieldType::Object(BTreeMap::new()),
+        _ => match structs.get(&name) {
+            Some(item_struct) => struct_field_type(item_struct, structs, depth + 1),
+            None => FieldType::Any,
+        },
+    }
+}
+
+fn struct_field_type(item_struct: &ItemStruct, structs: &HashMap<String, &ItemStruct>, depth: usize) -> FieldType {
+    match &item_struct.fields {
+        Fields::Named(named_fields) => {
+            let field_types: BTreeMap<String, FieldType> = named_fields
+                .named
+                .iter()
+                .filter_map(|field| {
+                    let name = field.ident.as_ref()?.to_string();
+                    Some((name, field_type(&field.ty, structs, depth)))
+                })
+                .collect();
+            FieldType::Object(field_types)
+        },
+        Fields::Unnamed(unnamed_fields) if unnamed_fields.unnamed.len() == 1 => {
+            field_type(&unnamed_fields.unnamed[0].ty, structs, depth)
+        },
+        _ => FieldType::Any,
+    }
+}
+
+// Find the body type of a `web::Json<T>` handler argument.
+fn request_body_type(handler: &ItemFn, structs: &HashMap<String, &ItemStruct>) -> Option<FieldType> {
+    handler
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(typed_argument) => Some(typed_argument.ty.as_ref()),
+            FnArg::Receiver(_) => None,
+        })
+        .find_map(|argument_type| match type_path_segment(argument_type) {
+            Some((name, generic_types)) if name == "Json" => generic_types
+                .first()
+                .map(|body_type| field_type(body_type, structs, 0)),
+            _ => None,
+        })
+}
+
+// Describe a type unless nothing is known about it.
+fn known_field_type(rust_type: &Type, structs: &HashMap<String, &ItemStruct>) -> Option<FieldType> {
+    Some(field_type(rust_type, structs, 0)).filter(|field_type| *field_type != FieldType::Any)
+}
+
+// Get the type a function returns, treating `Result<T, E>` as `T` the way `?` does.
+fn signature_return_type(signature: &Signature, structs: &HashMap<String, &ItemStruct>) -> Option<FieldType> {
+    let ReturnType::Type(_, return_type) = &signature.output else {
+        return None;
+    };
+    match type_path_segment(return_type) {
+        Some((name, generic_types)) if name == "Result" && !generic_types.is_empty() => {
+            known_field_type(generic_types[0], structs)
+        },
+        _ => known_field_type(return_type, structs),
+    }
+}
+
+// Whether a function is one of the `HttpResponse` constructors, such as `HttpResponse::Ok`.
+fn is_http_response_constructor(function: &Expr) -> bool {
+    let Expr::Path(expression_path) = function else { return false };
+    expression_path
+        .path
+        .segments
+        .iter()
+        .any(|segment| segment.ident == "HttpResponse" || segment.ident == "HttpResponseBuilder")
+}
+
+// Whether a builder chain starts from `HttpResponse::Ok()` or a similar constructor.
+fn is_http_response_builder(expression: &Expr) -> bool {
+    let mut current = expression;
+    while let Expr::MethodCall(method_call) = current {
+        current = method_call.receiver.as_ref();
+    }
+    matches!(current, Expr::Call(ExprCall { func, .. }) if is_http_response_constructor(func))
+}
+
+// Infer the bodies a handler responds with, tracking the types of its local variables.
+struct HandlerResponseCollector<'a, 'ast> {
+    structs: &'a HashMap<String, &'ast ItemStruct>,
+    functions: &'a HashMap<String, &'ast Signature>,
+    local_types: HashMap<String, FieldType>,
+    // One entry per `.json(..)` or `web::Json(..)` response, `None` when its type is unknown.
+    response_types: Vec<Option<FieldType>>,
+    status_codes: Vec<u16>,
+}
+
+impl<'a, 'ast> HandlerResponseCollector<'a, 'ast> {
+    fn new(structs: &'a HashMap<String, &'ast ItemStruct>, functions: &'a HashMap<String, &'ast Signature>) -> Self {
+        Self {
+            structs,
+            functions,
+            local_types: HashMap::new(),
+            response_types: vec![],
+            status_codes: vec![],
+        }
+    }
+
+    fn function_return_type(&self, name: &str) -> Option<FieldType> {
+        self.functions
+            .get(name)
+            .and_then(|signature| signature_return_type(signature, self.structs))
+    }
+
+    fn expression_type(&self, expression: &Expr) -> Option<FieldType> {
+        match expression {
+            Expr::Path(expression_path) => {
+                let identifier = expression_path.path.get_ident()?;
+                self.local_types.get(&identifier.to_string()).cloned()
+            },
+            Expr::Reference(reference) => self.expression_type(&reference.expr),
+            Expr::Paren(parenthesized) => self.expression_type(&parenthesized.expr),
+            Expr::Try(expression_try) => self.expression_type(&expression_try.expr),
+            Expr::Await(expression_await) => self.expression_type(&expression_await.base),
+            Expr::Struct(expression_struct) => {
+                let name = expression_struct.path.segments.last()?.ident.to_string();
+                self.structs
+                    .get(&name)
+                    .map(|item_struct| struct_field_type(item_struct, self.structs, 1))
+            },
+            Expr::Lit(expression_literal) => match &expression_literal.lit {
+                Lit::Str(_) => Some(FieldType::String),
+                Lit::Int(_) => Some(FieldType::Integer),
+                Lit::Float(_) => Some(FieldType::Number),
+                Lit::Bool(_) => Some(FieldType::Bool),
+                _ => None,
+            },
+            Expr::Array(expression_array) => {
+                let element_type = self.expression_type(expression_array.elems.first()?)?;
+                Some(FieldType::Array(Box::new(element_type)))
+            },
+            Expr::Macro(expression_macro) if expression_macro.mac.path.is_ident("vec") => {
+                let elements = expression_macro
+                    .mac
+                    .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+                    .ok()?;
+                let element_type = self.expression_type(elements.first()?)?;
+                Some(FieldType::Array(Box::new(element_type)))
+            },
+            Expr::Call(ExprCall { func, args, .. }) => {
+                let Expr::Path(function_path) = func.as_ref() else { return None };
+                let segments: Vec<String> = function_path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|segment| segment.ident.to_string())
+                    .collect();
+                match segments.as_slice() {
+                    [.., name] if name == "Json" || name == "Ok" => self.expression_type(args.first()?),
+                    [.., name] if name == "Some" => {
+                        Some(FieldType::Nullable(Box::new(self.expression_type(args.first()?)?)))
+                    },
+                    [.., type_name, constructor] if matches!(constructor.as_str(), "new" | "default")
+                        && self.structs.contains_key(type_name) =>
+                    {
+                        Some(struct_field_type(self.structs[type_name], self.structs, 1))
+                    },
+                    [.., name] => self.function_return_type(name),
+                    [] => None,
+                }
+            },
+            Expr::MethodCall(method_call) => match method_call.method.to_string().as_str() {
+                "clone" | "cloned" | "copied" | "to_owned" | "to_vec" | "into_inner" | "into" => {
+                    self.expression_type(&method_call.receiver)
+                },
+                "unwrap" | "expect" | "unwrap_or" | "unwrap_or_else" | "unwrap_or_default" => {
+                    match self.expression_type(&method_call.receiver)? {
+                        FieldType::Nullable(inner_type) => Some(*inner_type),
+                        receiver_type => Some(receiver_type),
+                    }
+                },
+                "to_string" | "format" => Some(FieldType::String),
+                "len" | "count" => Some(FieldType::Integer),
+                name => self.function_return_type(name),
+            },
+            _ => None,
+        }
+    }
+
+    // Record the types of the variables a pattern binds, given the type of the matched value.
+    fn bind_pattern(&mut self, pattern: &Pat, value_type: Option<FieldType>) {
+        match pattern {
+            Pat::Ident(pattern_identifier) => {
+                let name = pattern_identifier.ident.to_string();
+                match value_type {
+                    Some(value_type) => self.local_types.insert(name, value_type),
+                    None => self.local_types.remove(&name),
+                };
+            },
+            Pat::Type(pattern_type) => {
+                let annotated_type = known_field_type(&pattern_type.ty, self.structs);
+                self.bind_pattern(&pattern_type.pat, annotated_type);
+            },
+            Pat::Reference(pattern_reference) => self.bind_pattern(&pattern_reference.pat, value_type),
+            Pat::Paren(pattern_parenthesized) => self.bind_pattern(&pattern_parenthesized.pat, value_type),
+            Pat::TupleStruct(pattern_tuple_struct) => {
+                let variant = pattern_tuple_struct.path.segments.last().map(|segment| segment.ident.to_string());
+                let inner_type = match (variant.as_deref(), value_type) {
+                    (Some("Some"), Some(FieldType::Nullable(inner_type))) => Some(*inner_type),
+                    (Some("Some" | "Ok"), value_type) => value_type,
+                    _ => None,
+                };
+                for (index, element) in pattern_tuple_struct.elems.iter().enumerate() {
+                    self.bind_pattern(element, if index == 0 { inner_type.clone() } else { None });
+                }
+            },
+            Pat::Struct(pattern_struct) => {
+                for field in &pattern_struct.fields {
+                    let field_type = match (&field.member, &value_type) {
+                        (Member::Named(name), Some(FieldType::Object(field_types))) => {
+                            field_types.get(&name.to_string()).cloned()
+                        },
+                        _ => None,
+                    };
+                    self.bind_pattern(&field.pat, field_type);
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for HandlerResponseCollector<'_, 'ast> {
+    // Nested items have their own scope, and never respond for the handler.
+    fn visit_item(&mut self, _item: &'ast Item) {}
+
+    fn visit_local(&mut self, local: &'ast Local) {
+        visit::visit_local(self, local);
+        let value_type = local.init.as_ref().and_then(|init| self.expression_type(&init.expr));
+        self.bind_pattern(&local.pat, value_type);
+    }
+
+    fn visit_expr_let(&mut self, expression_let: &'ast ExprLet) {
+        visit::visit_expr_let(self, expression_let);
+        let value_type = self.expression_type(&expression_let.expr);
+        self.bind_pattern(&expression_let.pat, value_type);
+    }
+
+    fn visit_expr_match(&mut self, expression_match: &'ast ExprMatch) {
+        self.visit_expr(&expression_match.expr);
+        let value_type = self.expression_type(&expression_match.expr);
+        for arm in &expression_match.arms {
+            self.bind_pattern(&arm.pat, value_type.clone());
+            self.visit_arm(arm);
+        }
+    }
+
+    fn visit_expr_method_call(&mut self, method_call: &'ast ExprMethodCall) {
+        visit::visit_expr_method_call(self, method_call);
+        if method_call.method == "json" && method_call.args.len() == 1 && is_http_response_builder(&method_call.receiver) {
+            let response_type = self.expression_type(&method_call.args[0]);
+            self.response_types.push(response_type);
+        }
+    }
+
+    fn visit_expr_call(&mut self, expression_call: &'ast ExprCall) {
+        visit::visit_expr_call(self, expression_call);
+        if last_path_segment(&expression_call.func).as_deref() == Some("Json") && expression_call.args.len() == 1 {
+            let response_type = self.expression_type(&expression_call.args[0]);
+            self.response_types.push(response_type);
+        }
+
+        // `HttpResponse::Created()`, or `HttpResponse::build(StatusCode::CREATED)`.
+        if is_http_response_constructor(&expression_call.func) {
+            let status_code = match last_path_segment(&expression_call.func).as_deref() {
+                Some("build" | "new") => expression_call.args
+                    .first()
+                    .and_then(last_path_segment)
+                    .and_then(|constant| SUCCESS_STATUS_CODES.iter().find(|(_, name, _)| *name == constant)),
+                Some(constructor) => SUCCESS_STATUS_CODES.iter().find(|(name, _, _)| *name == constructor),
+                None => None,
+            };
+            if let Some((_, _, status_code)) = status_code
+                && !self.status_codes.contains(status_code)
+            {
+                self.status_codes.push(*status_code);
+            }
+        }
+    }
+}
+
+// What a handler responds with when it succeeds.
+struct HandlerResponses {
+    // `Ok(None)` when it sends no body, `Err` when the type of its body is unknown.
+    body_type: Result<Option<FieldType>, String>,
+    // Empty when the handler does not say, in which case any 2xx status is expected.
+    status_codes: Vec<u16>,
+}
+
+fn handler_responses(
+    handler: &ItemFn,
+    structs: &HashMap<String, &ItemStruct>,
+    functions: &HashMap<String, &Signature>,
+) -> HandlerResponses {
+    // A handler returning `web::Json<T>`, possibly wrapped in a `Result`, declares its response.
+    if let ReturnType::Type(_, return_type) = &handler.sig.output {
+        let mut current_type = return_type.as_ref();
+        while let Some((name, generic_types)) = type_path_segment(current_type) {
+            match name.as_str() {
+                "Result" if !generic_types.is_empty() => current_type = generic_types[0],
+                "Json" if !generic_types.is_empty() => return HandlerResponses {
+                    body_type: Ok(Some(field_type(generic_types[0], structs, 0))),
+                    status_codes: vec![200],
+                },
+                _ => break,
+            }
+        }
+    }
+
+    // Otherwise, find what `HttpResponse::Ok().json(value)` and the like send.
+    let mut collector = HandlerResponseCollector::new(structs, functions);
+    for input in &handler.sig.inputs {
+        if let FnArg::Typed(typed_argument) = input {
+            let argument_type = known_field_type(&typed_argument.ty, structs);
+            collector.bind_pattern(&typed_argument.pat, argument_type);
+        }
+    }
+    collector.visit_block(&handler.block);
+    let body_type = if collector.response_types.iter().any(Option::is_none) {
+        Err(format!("Unable to infer the response type of handler `{}`.", handler.sig.ident))
+    } else {
+        Ok(collector.response_types.into_iter().flatten().next())
+    };
+    HandlerResponses { body_type, status_codes: collector.status_codes }
+}
+
+// Find the types of the path parameters a handler extracts with `web::Path<T>`.
+fn path_parameter_types(
+    handler: &ItemFn,
+    path_parameters: &[String],
+    structs: &HashMap<String, &ItemStruct>,
+) -> BTreeMap<String, FieldType> {
+    let path_type = handler
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(typed_argument) => Some(typed_argument.ty.as_ref()),
+            FnArg::Receiver(_) => None,
+        })
+        .find_map(|argument_type| match type_path_segment(argument_type) {
+            Some((name, generic_types)) if name == "Path" => generic_types.first().copied(),
+            _ => None,
+        });
+    let Some(path_type) = path_type else {
+        return BTreeMap::new();
+    };
+
+    // Tuples extract parameters in order, and structs by name.
+    match (path_type, field_type(path_type, structs, 0)) {
+        (Type::Tuple(tuple_type), _) => path_parameters
+            .iter()
+            .cloned()
+            .zip(tuple_type.elems.iter().map(|element_type| field_type(element_type, structs, 0)))
+            .collect(),
+        (_, FieldType::Object(field_types)) => field_types
+            .into_iter()
+            .filter(|(name, _)| path_parameters.contains(name))
+            .collect(),
+        (_, parameter_type) if path_parameters.len() == 1 => BTreeMap::from([(path_parameters[0].clone(), parameter_type)]),
+        _ => BTreeMap::new(),
+    }
+}
+
+// Derive the endpoint schema from actix-web code without calling the LLM.
+pub fn analyze_rest_api_endpoints(code: &str) -> Result<RestApiAnalysis, syn::Error> {
+    let file = syn::parse_file(code)?;
+    let mut collector = RouteCollector::default();
+    collector.visit_file(&file);
+
+    let mut unresolved = collector.unresolved.clone();
+    let mut endpoints: Vec<EndpointRoute> = vec![];
+    for registration in &collector.registrations {
+        let Some(handler) = collector.handlers.get(&registration.handler) else {
+            unresolved.push(format!("Handler `{}` for route `{}` was not found.", registration.handler, registration.route));
+            continue;
+        };
+        let responses = handler_responses(handler, &collector.structs, &collector.functions);
+        let response = match responses.body_type {
+            Ok(response) => response,
+            Err(message) => {
+                unresolved.push(format!("{} It serves route `{}`.", message, registration.route));
+                None
+            },
+        };
+        let mut endpoint = EndpointRoute::new(
+            registration.method.clone(),
+            registration.route.clone(),
+            request_body_type(handler, &collector.structs),
+            response,
+        );
+        endpoint.path_parameter_types = path_parameter_types(handler, &endpoint.path_parameters, &collector.structs);
+        endpoint.status_codes = responses.status_codes;
+        endpoints.push(endpoint);
+    }
+
+    Ok(RestApiAnalysis { endpoints, unresolved })
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utilities::backend_code_persistence::BackendCodePersistence;
+
+    #[test]
+    fn test_analyze_code_template() {
+        let code_template = BackendCodePersistence::read_code_template_contents();
+        let analysis = analyze_rest_api_endpoints(&code_template)
+            .expect("Failed to parse code template.");
+        assert!(analysis.is_complete());
+
+        let routes: Vec<(HTTPMethod, &str)> = analysis
+            .endpoints
+            .iter()
+            .map(|endpoint| (endpoint.method.clone(), endpoint.route.as_str()))
+            .collect();
+        assert_eq!(routes, vec![
+            (HTTPMethod::Post, "/task"),
+            (HTTPMethod::Get, "/task"),
+            (HTTPMethod::Put, "/task"),
+            (HTTPMethod::Get, "/task/{id}"),
+            (HTTPMethod::Delete, "/task/{id}"),
+            (HTTPMethod::Post, "/register"),
+            (HTTPMethod::Post, "/login"),
+        ]);
+
+        let create_task = &analysis.endpoints[0];
+        assert!(!create_task.is_route_dynamic);
+        assert_eq!(
+            create_task.request_body,
+            Some(FieldType::Object(BTreeMap::from([
+                ("id".to_string(), FieldType::Integer),
+                ("name".to_string(), FieldType::String),
+                ("completed".to_string(), FieldType::Bool),
+            ]))),
+        );
+        assert_eq!(create_task.response, None);
+        assert_eq!(create_task.status_codes, vec![200]);
+        let task = create_task.request_body.clone().expect("Missing task type.");
+        assert_eq!(analysis.endpoints[1].response, Some(FieldType::Array(Box::new(task.clone()))));
+        assert!(analysis.endpoints[3].is_route_dynamic);
+        assert_eq!(analysis.endpoints[3].request_body, None);
+        assert_eq!(analysis.endpoints[3].response, Some(task));
+        assert_eq!(
+            analysis.endpoints[3].path_parameter_types,
+            BTreeMap::from([("id".to_string(), FieldType::Integer)]),
+        );
+    }
+
+    #[test]
+    fn test_analyze_scopes_resources_and_attributes() {
+        let code = r#"
+            struct Price { symbol: String, value: Option<f64>, history: Vec<f64> }
+
+            #[get("/health")]
+            async fn health() -> impl Responder { HttpResponse::Ok().finish() }
+
+            async fn price(symbol: web::Path<String>) -> Result<web::Json<Price>, Error> { todo!() }
+
+            async fn update_price(price: web::Json<Price>) -> impl Responder {
+                match price.value {
+                    Some(_) => HttpResponse::Created().json(price.into_inner()),
+                    None => HttpResponse::build(StatusCode::NO_CONTENT).finish(),
+                }
+            }
+
+            async fn latest_prices(path: web::Path<(String, u32)>) -> impl Responder {
+                let prices = vec![Price { symbol: path.0.clone(), value: None, history: vec![] }];
+                HttpResponse::Ok().content_type("application/json").json(prices)
+            }
+
+            async fn statistics() -> impl Responder {
+                HttpResponse::Ok().json(serde_json::json!({ "count": 1 }))
+            }
+
+            fn main() {
+                App::new()
+                    .service(health)
+                    .service(web::scope("/api").route("/price/{symbol}", web::get().to(price)))
+                    .service(web::resource("/price").route(web::put().to(update_price)))
+                    .service(web::scope("/api").service(
+                        web::scope("/v1")
+                            .route("/prices/{symbol}/{count}", web::get().to(latest_prices))
+                            .service(web::resource("/statistics").route(web::get().to(statistics)))
+                    ))
+                    .route("/missing", web::get().to(not_defined));
+            }
+        "#;
+        let analysis = analyze_rest_api_endpoints(code)
+            .expect("Failed to parse test code.");
+
+        let routes: Vec<(HTTPMethod, &str)> = analysis
+            .endpoints
+            .iter()
+            .map(|endpoint| (endpoint.method.clone(), endpoint.route.as_str()))
+            .collect();
+        assert_eq!(routes, vec![
+            (HTTPMethod::Get, "/health"),
+            (HTTPMethod::Get, "/api/price/{symbol}"),
+            (HTTPMethod::Put, "/price"),
+            (HTTPMethod::Get, "/api/v1/prices/{symbol}/{count}"),
+            (HTTPMethod::Get, "/api/v1/statistics"),
+        ]);
+        let price = FieldType::Object(BTreeMap::from([
+            ("symbol".to_string(), FieldType::String),
+            ("value".to_string(), FieldType::Nullable(Box::new(FieldType::Number))),
+            ("history".to_string(), FieldType::Array(Box::new(FieldType::Number))),
+        ]));
+        assert_eq!(analysis.endpoints[1].response, Some(price.clone()));
+        assert_eq!(analysis.endpoints[1].path_parameters, vec!["symbol".to_string()]);
+        assert_eq!(
+            analysis.endpoints[1].path_parameter_types,
+            BTreeMap::from([("symbol".to_string(), FieldType::String)]),
+        );
+        assert_eq!(analysis.endpoints[1].status_codes, vec![200]);
+        assert_eq!(analysis.endpoints[2].response, Some(price.clone()));
+        assert_eq!(analysis.endpoints[2].status_codes, vec![201, 204]);
+        assert_eq!(analysis.endpoints[3].response, Some(FieldType::Array(Box::new(price))));
+        assert_eq!(
+            analysis.endpoints[3].path_parameter_types,
+            BTreeMap::from([
+                ("symbol".to_string(), FieldType::String),
+                ("count".to_string(), FieldType::Integer),
+            ]),
+        );
+        assert_eq!(analysis.endpoints[4].response, None);
+
+        // The missing handler, and the `json!` response whose type is unknown.
+        assert_eq!(analysis.unresolved.len(), 2);
+        assert!(analysis.unresolved.iter().any(|message| message.contains("`statistics`")));
+        assert!(!analysis.is_complete());
+    }
+}
@@ -0,0 +1,51 @@
+/*
+ *  utilities/run_directory.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use std::path::{ Path, PathBuf };
+use std::sync::OnceLock;
+
+pub const DEFAULT_RUNS_DIRECTORY: &str = "runs";
+const STATE_FILE_NAME: &str = "state.json";
+const EVENT_LOG_FILE_NAME: &str = "events.jsonl";
+const SERVER_LOG_FILE_NAME: &str = "backend_server.log";
+
+static RUNS_DIRECTORY: OnceLock<PathBuf> = OnceLock::new();
+
+// Where each run keeps its transcript, state, events and report.
+pub struct RunDirectory {}
+
+impl RunDirectory {
+    // Keep runs somewhere else than `runs`; only the first call has an effect.
+    pub fn set_runs_directory(runs_directory: PathBuf) {
+        _ = RUNS_DIRECTORY.set(runs_directory);
+    }
+
+    pub fn get_runs_directory() -> PathBuf {
+        RUNS_DIRECTORY.get()
+            .cloned()
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_RUNS_DIRECTORY))
+    }
+
+    pub fn get_run_directory(run_id: &str) -> PathBuf {
+        Self::get_runs_directory().join(run_id)
+    }
+
+    pub fn get_state_file_path(run_directory: &Path) -> PathBuf {
+        run_directory.join(STATE_FILE_NAME)
+    }
+
+    pub fn get_event_log_path(run_directory: &Path) -> PathBuf {
+        run_directory.join(EVENT_LOG_FILE_NAME)
+    }
+
+    // The output of the backend server during the run, copied from the backend project when the run ends.
+    pub fn get_server_log_path(run_directory: &Path) -> PathBuf {
+        run_directory.join(SERVER_LOG_FILE_NAME)
+    }
+}
@@ -0,0 +1,582 @@
+/*
+ *  utilities/run_report.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::run_directory::RunDirectory;
+use super::transcripts::markdown_fence;
+use super::event_bus::{ AgentEvent, EventSink };
+
+use crate::model::backend_task::backend_task_progress;
+use crate::model::endpoint_route::HTTPMethod;
+use crate::model::project::Project;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{ Path, PathBuf };
+use std::sync::Mutex;
+
+const SERVER_LOG_TAIL_LINE_COUNT: usize = 20;
+const MAXIMUM_DIAGNOSTICS_LENGTH: usize = 2000;
+const MARKDOWN_REPORT_FILE_NAME: &str = "report.md";
+const HTML_REPORT_FILE_NAME: &str = "report.html";
+
+// Keeps the events of a run, to report on once it is over.
+pub struct RunEventCollector {
+    events: Mutex<Vec<AgentEvent>>,
+}
+
+impl RunEventCollector {
+    pub fn new() -> Self {
+        Self {
+            events: Mutex::new(vec![]),
+        }
+    }
+
+    pub fn events(&self) -> Vec<AgentEvent> {
+        self.events.lock()
+            .map(|events| events.clone())
+            .unwrap_or_default()
+    }
+}
+
+impl Default for RunEventCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventSink for RunEventCollector {
+    fn handle_event(&self, event: &AgentEvent) {
+        if let Ok(mut events) = self.events.lock() {
+            events.push(event.clone());
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReportBlock {
+    Paragraph(String),
+    List(Vec<String>),
+    Table {
+        headers: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+    Code(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportSection {
+    pub title: String,
+    pub blocks: Vec<ReportBlock>,
+}
+
+impl ReportSection {
+    fn new(title: &str, blocks: Vec<ReportBlock>) -> Self {
+        Self {
+            title: title.to_string(),
+            blocks,
+        }
+    }
+}
+
+fn table(headers: &[&str], rows: Vec<Vec<String>>) -> ReportBlock {
+    ReportBlock::Table {
+        headers: headers.iter().map(|header| header.to_string()).collect(),
+        rows,
+    }
+}
+
+fn paragraph(text: &str) -> ReportBlock {
+    ReportBlock::Paragraph(text.to_string())
+}
+
+fn truncated(text: &str, maximum_length: usize) -> String {
+    match text.char_indices().nth(maximum_length) {
+        Some((index, _)) => format!("{}…", &text[..index]),
+        None => text.to_string(),
+    }
+}
+
+fn goal_section(project: &Project) -> ReportSection {
+    let mut blocks = vec![paragraph(&project.description)];
+    if let Some(requirements) = &project.requirements {
+        blocks.push(paragraph(&format!("Requested as: {}", requirements.request)));
+        let answers: Vec<String> = requirements.clarifications
+            .iter()
+            .filter(|clarification| !clarification.answer.is_empty())
+            .map(|clarification| format!("{} {}", clarification.question, clarification.answer))
+            .collect();
+        if !answers.is_empty() {
+            blocks.push(ReportBlock::List(answers));
+        }
+    }
+    ReportSection::new("Goal", blocks)
+}
+
+fn scope_section(project: &Project) -> ReportSection {
+    let Some(scope) = project.scope else {
+        return ReportSection::new("Scope", vec![paragraph("The scope was not determined.")]);
+    };
+    let answer = |is_required: bool| if is_required { "Yes" } else { "No" }.to_string();
+    ReportSection::new("Scope", vec![table(&["Requirement", "Needed"], vec![
+        vec!["CRUD".to_string(), answer(scope.is_crud_required)],
+        vec!["User login and logout".to_string(), answer(scope.is_user_login_and_logout_required)],
+        vec!["External URLs".to_string(), answer(scope.is_external_urls_required)],
+    ])])
+}
+
+fn external_urls_section(project: &Project, events: &[AgentEvent]) -> ReportSection {
+    let rows: Vec<Vec<String>> = events
+        .iter()
+        .filter_map(|event| match event {
+            AgentEvent::ExternalUrlChecked { url, status_code, excluded, .. } => Some(vec![
+                url.clone(),
+                status_code.map_or("unreachable".to_string(), |status_code| status_code.to_string()),
+                if *excluded { "Excluded" } else { "Kept" }.to_string(),
+            ]),
+            _ => None,
+        })
+        .collect();
+    if !rows.is_empty() {
+        return ReportSection::new("External URLs", vec![table(&["URL", "Status", "Result"], rows)]);
+    }
+    match project.external_urls.as_deref() {
+        Some(urls) if !urls.is_empty() => ReportSection::new("External URLs", vec![ReportBlock::List(urls.to_vec())]),
+        _ => ReportSection::new("External URLs", vec![paragraph("No external URLs were used.")]),
+    }
+}
+
+fn backend_tasks_section(project: &Project) -> ReportSection {
+    if project.backend_tasks.is_empty() {
+        return ReportSection::new("Backend Tasks", vec![paragraph("The backend was built without a task plan.")]);
+    }
+    let (done_count, task_count) = backend_task_progress(&project.backend_tasks);
+    let rows = project.backend_tasks
+        .iter()
+        .map(|backend_task| vec![
+            backend_task.title.clone(),
+            format!("{:?}", backend_task.kind),
+            format!("{:?}", backend_task.status),
+        ])
+        .collect();
+    ReportSection::new("Backend Tasks", vec![
+        paragraph(&format!("{} of {} tasks done.", done_count, task_count)),
+        table(&["Task", "Kind", "Status"], rows),
+    ])
+}
+
+fn fix_iterations_section(events: &[AgentEvent]) -> ReportSection {
+    let mut blocks: Vec<ReportBlock> = vec![];
+    for event in events {
+        if let AgentEvent::BugRecorded { position, iteration, diagnostics } = event {
+            blocks.push(paragraph(&format!("Fix iteration {} ({}):", iteration, position)));
+            blocks.push(ReportBlock::Code(truncated(diagnostics, MAXIMUM_DIAGNOSTICS_LENGTH)));
+        }
+    }
+    if blocks.is_empty() {
+        blocks.push(paragraph("No fixes were needed."));
+    }
+    ReportSection::new("Fix Iterations", blocks)
+}
+
+fn builds_section(events: &[AgentEvent]) -> ReportSection {
+    let rows: Vec<Vec<String>> = events
+        .iter()
+        .filter_map(|event| match event {
+            AgentEvent::BuildFinished { target, succeeded, .. } => Some(vec![
+                target.clone(),
+                if *succeeded { "Succeeded" } else { "Failed" }.to_string(),
+            ]),
+            _ => None,
+        })
+        .collect();
+    if rows.is_empty() {
+        return ReportSection::new("Builds", vec![paragraph("Nothing was built.")]);
+    }
+    ReportSection::new("Builds", vec![table(&["Target", "Result"], rows)])
+}
+
+// The latest result for each endpoint, since endpoints are tested again after every fix.
+fn endpoint_tests_section(events: &[AgentEvent]) -> ReportSection {
+    let mut latest_results: BTreeMap<(String, String), (HTTPMethod, bool, String)> = BTreeMap::new();
+    for event in events {
+        if let AgentEvent::EndpointTested { method, route, passed, detail, .. } = event {
+            latest_results.insert(
+                (route.clone(), format!("{:?}", method)),
+                (method.clone(), *passed, detail.clone()),
+            );
+        }
+    }
+    if latest_results.is_empty() {
+        return ReportSection::new("Endpoint Tests", vec![paragraph("No endpoints were tested.")]);
+    }
+    let passed_count = latest_results.values().filter(|(_, passed, _)| *passed).count();
+    let rows = latest_results
+        .into_iter()
+        .map(|((route, _), (method, passed, detail))| vec![
+            format!("{:?}", method),
+            route,
+            if passed { "Pass" } else { "Fail" }.to_string(),
+            detail,
+        ])
+        .collect::<Vec<Vec<String>>>();
+    ReportSection::new("Endpoint Tests", vec![
+        paragraph(&format!("{} of {} endpoints passed.", passed_count, rows.len())),
+        table(&["Method", "Route", "Result", "Detail"], rows),
+    ])
+}
+
+// Calls and tokens of an agent, with their cost when the model of every call is known.
+#[derive(Default)]
+struct TokenUsageTotals {
+    calls: u32,
+    prompt_tokens: u32,
+    completion_tokens: u32,
+    total_tokens: u32,
+    cost: f64,
+    has_unpriced_calls: bool,
+}
+
+impl TokenUsageTotals {
+    fn add(&mut self, other: &TokenUsageTotals) {
+        self.calls += other.calls;
+        self.prompt_tokens += other.prompt_tokens;
+        self.completion_tokens += other.completion_tokens;
+        self.total_tokens += other.total_tokens;
+        self.cost += other.cost;
+        self.has_unpriced_calls |= other.has_unpriced_calls;
+    }
+
+    fn row(&self, name: String) -> Vec<String> {
+        let cost = if self.has_unpriced_calls { "Unknown".to_string() } else { format!("${:.4}", self.cost) };
+        vec![
+            name,
+            self.calls.to_string(),
+            self.prompt_tokens.to_string(),
+            self.completion_tokens.to_string(),
+            self.total_tokens.to_string(),
+            cost,
+        ]
+    }
+}
+
+fn token_usage_section(events: &[AgentEvent]) -> ReportSection {
+    let mut usage_by_position: BTreeMap<String, TokenUsageTotals> = BTreeMap::new();
+    for event in events {
+        if let AgentEvent::LlmCallFinished { position, model, usage, .. } = event {
+            let totals = usage_by_position.entry(position.clone()).or_default();
+            totals.calls += 1;
+            if let Some(usage) = usage {
+                totals.prompt_tokens += usage.prompt_tokens;
+                totals.completion_tokens += usage.completion_tokens;
+                totals.total_tokens += usage.total_tokens;
+                match model {
+                    Some(model) => totals.cost += model.cost(usage),
+                    None => totals.has_unpriced_calls = true,
+                }
+            }
+        }
+    }
+    if usage_by_position.is_empty() {
+        return ReportSection::new("Token Usage and Cost", vec![paragraph("No LLM calls were made.")]);
+    }
+
+    let mut overall_totals = TokenUsageTotals::default();
+    let mut rows: Vec<Vec<String>> = vec![];
+    for (position, totals) in usage_by_position {
+        overall_totals.add(&totals);
+        rows.push(totals.row(position));
+    }
+    rows.push(overall_totals.row("Total".to_string()));
+    ReportSection::new(
+        "Token Usage and Cost",
+        vec![table(&["Agent", "Calls", "Prompt Tokens", "Completion Tokens", "Total Tokens", "Cost (USD)"], rows)],
+    )
+}
+
+fn artifacts_section(events: &[AgentEvent]) -> ReportSection {
+    let mut artifacts: Vec<String> = vec![];
+    let mut warnings: Vec<String> = vec![];
+    let mut failed_checks: Vec<String> = vec![];
+    for event in events {
+        match event {
+            AgentEvent::AgentFinished { position, report: Some(report), .. } => {
+                artifacts.extend(report.artifacts.iter().map(|artifact| format!("{}: {}", position, artifact)));
+                warnings.extend(report.warnings.iter().map(|warning| format!("{}: {}", position, warning)));
+                failed_checks.extend(report.failed_checks.iter().map(|failed_check| format!("{}: {}", position, failed_check)));
+            },
+            AgentEvent::AgentFinished { position, error: Some(error), .. } => {
+                warnings.push(format!("{} failed: {}", position, error));
+            },
+            AgentEvent::AgentSkipped { position } => {
+                warnings.push(format!("{} was skipped.", position));
+            },
+            _ => {},
+        }
+    }
+
+    let mut blocks = vec![];
+    if artifacts.is_empty() {
+        blocks.push(paragraph("No artifacts were produced."));
+    } else {
+        blocks.push(ReportBlock::List(artifacts));
+    }
+    if !failed_checks.is_empty() {
+        blocks.push(paragraph("Failed checks:"));
+        blocks.push(ReportBlock::List(failed_checks));
+    }
+    if !warnings.is_empty() {
+        blocks.push(paragraph("Warnings:"));
+        blocks.push(ReportBlock::List(warnings));
+    }
+    ReportSection::new("Artifacts", blocks)
+}
+
+fn open_issues_section(project: &Project) -> ReportSection {
+    let mut issues: Vec<String> = project.defects
+        .iter()
+        .map(|defect| defect.summary())
+        .collect();
+    issues.extend(project.review_issues.iter().map(|review_issue| review_issue.summary()));
+    if issues.is_empty() {
+        return ReportSection::new("Open Issues", vec![paragraph("No defects or review issues are open.")]);
+    }
+    ReportSection::new("Open Issues", vec![ReportBlock::List(issues)])
+}
+
+fn server_log_section(server_log: Option<&str>) -> ReportSection {
+    let Some(server_log) = server_log else {
+        return ReportSection::new("Server Log", vec![paragraph("The server never ran.")]);
+    };
+    let lines: Vec<&str> = server_log.lines().collect();
+    let tail = lines[lines.len().saturating_sub(SERVER_LOG_TAIL_LINE_COUNT)..].join("\n");
+    ReportSection::new("Server Log", vec![
+        paragraph("The last lines of the backend server log:"),
+        ReportBlock::Code(tail),
+    ])
+}
+
+// Summarize a run from the final project and the events published along the way.
+pub fn run_report_sections(project: &Project, events: &[AgentEvent], server_log: Option<&str>) -> Vec<ReportSection> {
+    vec![
+        goal_section(project),
+        scope_section(project),
+        external_urls_section(project, events),
+        backend_tasks_section(project),
+        fix_iterations_section(events),
+        builds_section(events),
+        endpoint_tests_section(events),
+        token_usage_section(events),
+        artifacts_section(events),
+        open_issues_section(project),
+        server_log_section(server_log),
+    ]
+}
+
+fn escape_markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+pub fn render_markdown(title: &str, sections: &[ReportSection]) -> String {
+    let mut markdown = format!("# {}\n", title);
+    for section in sections {
+        markdown.push_str(&format!("\n## {}\n", section.title));
+        for block in &section.blocks {
+            markdown.push('\n');
+            match block {
+                ReportBlock::Paragraph(text) => markdown.push_str(&format!("{}\n", text)),
+                ReportBlock::List(items) => {
+                    for item in items {
+                        markdown.push_str(&format!(" - {}\n", item.replace('\n', "\n   ")));
+                    }
+                },
+                ReportBlock::Table { headers, rows } => {
+                    markdown.push_str(&format!("| {} |\n", headers.join(" | ")));
+                    markdown.push_str(&format!("|{}\n", " --- |".repeat(headers.len())));
+                    for row in rows {
+                        let cells: Vec<String> = row.iter().map(|cell| escape_markdown_cell(cell)).collect();
+                        markdown.push_str(&format!("| {} |\n", cells.join(" | ")));
+                    }
+                },
+                ReportBlock::Code(code) => {
+                    let fence = markdown_fence(code);
+                    markdown.push_str(&format!("{}text\n{}\n{}\n", fence, code, fence));
+                },
+            }
+        }
+    }
+    markdown
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// A single HTML file with its styles inline, so it can be opened or shared on its own.
+pub fn render_html(title: &str, sections: &[ReportSection]) -> String {
+    let mut body = format!("<h1>{}</h1>\n", escape_html(title));
+    for section in sections {
+        body.push_str(&format!("<section>\n<h2>{}</h2>\n", escape_html(&section.title)));
+        for block in &section.blocks {
+            match block {
+                ReportBlock::Paragraph(text) => body.push_str(&format!("<p>{}</p>\n", escape_html(text))),
+                ReportBlock::List(items) => {
+                    body.push_str("<ul>\n");
+                    for item in items {
+                        body.push_str(&format!("<li>{}</li>\n", escape_html(item).replace('\n', "<br>")));
+                    }
+                    body.push_str("</ul>\n");
+                },
+                ReportBlock::Table { headers, rows } => {
+                    body.push_str("<table>\n<tr>");
+                    for header in headers {
+                        body.push_str(&format!("<th>{}</th>", escape_html(header)));
+                    }
+                    body.push_str("</tr>\n");
+                    for row in rows {
+                        body.push_str("<tr>");
+                        for cell in row {
+                            body.push_str(&format!("<td>{}</td>", escape_html(cell)));
+                        }
+                        body.push_str("</tr>\n");
+                    }
+                    body.push_str("</table>\n");
+                },
+                ReportBlock::Code(code) => body.push_str(&format!("<pre>{}</pre>\n", escape_html(code))),
+            }
+        }
+        body.push_str("</section>\n");
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n\
+        body {{ font-family: -apple-system, system-ui, sans-serif; margin: 2em auto; max-width: 60em; padding: 0 1em; color: #222; }}\n\
+        table {{ border-collapse: collapse; margin: 1em 0; }}\n\
+        th, td {{ border: 1px solid #ccc; padding: 0.3em 0.6em; text-align: left; vertical-align: top; }}\n\
+        th {{ background: #f3f3f3; }}\n\
+        pre {{ background: #f6f6f6; padding: 1em; overflow-x: auto; }}\n\
+        </style>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape_html(title),
+        body,
+    )
+}
+
+pub struct RunReport {}
+
+impl RunReport {
+    // Write the report of a run into its directory, as Markdown and as HTML; returns the HTML path.
+    pub fn write(run_directory: &Path, project: &Project, events: &[AgentEvent]) -> std::io::Result<PathBuf> {
+        let server_log = fs::read_to_string(RunDirectory::get_server_log_path(run_directory)).ok();
+        let sections = run_report_sections(project, events, server_log.as_deref());
+        let run_id = run_directory.file_name()
+            .map_or(run_directory.to_string_lossy(), |file_name| file_name.to_string_lossy());
+        let title = format!("Run {}", run_id);
+
+        fs::create_dir_all(run_directory)?;
+        fs::write(run_directory.join(MARKDOWN_REPORT_FILE_NAME), render_markdown(&title, &sections))?;
+        let html_report_path = run_directory.join(HTML_REPORT_FILE_NAME);
+        fs::write(&html_report_path, render_html(&title, &sections))?;
+        Ok(html_report_path)
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::open_ai::request::Model;
+    use crate::api::open_ai::response::APIUsage;
+
+    #[test]
+    fn test_run_report() {
+        let project = Project {
+            description: "Build a <task> tracker".to_string(),
+            requirements: None,
+            scope: None,
+            external_urls: None,
+            backend_code: None,
+            backend_tasks: vec![],
+            api_endpoint_schema: None,
+            server_address: None,
+            frontend_code: None,
+            review_issues: vec![],
+            defects: vec![],
+        };
+        let usage = |tokens: u32| Some(APIUsage {
+            prompt_tokens: tokens,
+            completion_tokens: tokens,
+            total_tokens: 2 * tokens,
+        });
+        let events = vec![
+            AgentEvent::ExternalUrlChecked {
+                position: "Solutions Architect".to_string(),
+                url: "https://example.com".to_string(),
+                status_code: Some(404),
+                excluded: true,
+            },
+            AgentEvent::LlmCallFinished {
+                position: "Backend Developer".to_string(),
+                operation: "print_backend_code".to_string(),
+                model: Some(Model::GPT_5_Mini),
+                succeeded: true,
+                usage: usage(10),
+            },
+            AgentEvent::LlmCallFinished {
+                position: "Backend Developer".to_string(),
+                operation: "print_fixed_code".to_string(),
+                model: Some(Model::GPT_5_Mini),
+                succeeded: true,
+                usage: usage(5),
+            },
+            AgentEvent::LlmCallFinished {
+                position: "Solutions Architect".to_string(),
+                operation: "print_project_scope".to_string(),
+                model: None,
+                succeeded: true,
+                usage: usage(1000),
+            },
+            AgentEvent::EndpointTested {
+                position: "Backend Developer".to_string(),
+                method: HTTPMethod::Get,
+                route: "/task/{id}".to_string(),
+                passed: false,
+                detail: "status 500".to_string(),
+            },
+            AgentEvent::EndpointTested {
+                position: "Backend Developer".to_string(),
+                method: HTTPMethod::Get,
+                route: "/task/{id}".to_string(),
+                passed: true,
+                detail: "status 200".to_string(),
+            },
+        ];
+        let sections = run_report_sections(&project, &events, None);
+
+        let markdown = render_markdown("Run Report", &sections);
+        assert!(markdown.contains("| https://example.com | 404 | Excluded |"));
+        assert!(markdown.contains("| Backend Developer | 2 | 15 | 15 | 30 | $0.0000 |"));
+        assert!(markdown.contains("| Solutions Architect | 1 | 1000 | 1000 | 2000 | Unknown |"));
+        assert!(markdown.contains("| Total | 3 | 1015 | 1015 | 2030 | Unknown |"));
+        let usage = APIUsage {
+            prompt_tokens: 1_000_000,
+            completion_tokens: 500_000,
+            total_tokens: 1_500_000,
+        };
+        assert_eq!(Model::GPT_5_Mini.cost(&usage), 1.25);
+        assert!(markdown.contains("1 of 1 endpoints passed."));
+        assert!(markdown.contains("| Get | /task/{id} | Pass | status 200 |"));
+
+        let html = render_html("Run Report", &sections);
+        assert!(html.contains("<p>Build a &lt;task&gt; tracker</p>"));
+        assert!(html.starts_with("<!DOCTYPE html>"));
+    }
+}
@@ -0,0 +1,383 @@
+/*
+ *  utilities/rust_client_generator.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::code_naming::{ snake_case, upper_camel_case };
+
+use crate::model::endpoint_route::{ EndpointRoute, HTTPMethod };
+use crate::model::field_type::FieldType;
+use crate::model::route_path::RouteSegment;
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+pub const RUST_CLIENT_CRATE_NAME: &str = "backend_client";
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "static", "struct", "trait", "true", "type", "unsafe", "use", "where", "while",
+];
+
+// Keywords which cannot be raw identifiers, and get a trailing underscore instead.
+const RUST_PATH_KEYWORDS: &[&str] = &["crate", "self", "super"];
+
+// Argument holding the request body, which path parameters are renamed away from.
+const REQUEST_BODY_ARGUMENT: &str = "body";
+
+// Get a Rust identifier for a JSON field name, and whether serde needs to rename it.
+fn field_identifier(name: &str) -> (String, bool) {
+    let sanitized: String = snake_case(name)
+        .chars()
+        .map(|character| if character.is_ascii_alphanumeric() { character } else { '_' })
+        .collect();
+    let sanitized = match sanitized.chars().next() {
+        Some(first_character) if first_character.is_ascii_digit() => format!("_{}", sanitized),
+        None => "_".to_string(),
+        _ => sanitized,
+    };
+    if RUST_KEYWORDS.contains(&sanitized.as_str()) {
+        (format!("r#{}", sanitized), sanitized != name)
+    } else if RUST_PATH_KEYWORDS.contains(&sanitized.as_str()) {
+        (format!("{}_", sanitized), true)
+    } else {
+        let is_renamed = sanitized != name;
+        (sanitized, is_renamed)
+    }
+}
+
+// Number an identifier already used in the same scope, such as a second `created_at` becoming `created_at_2`.
+fn unique_identifier(identifier: String, used_identifiers: &mut HashSet<String>) -> String {
+    let mut unique_identifier = identifier.clone();
+    let mut number = 1;
+    while !used_identifiers.insert(unique_identifier.clone()) {
+        number += 1;
+        unique_identifier = format!("{}_{}", identifier.trim_start_matches("r#"), number);
+    }
+    unique_identifier
+}
+
+struct RustClientGenerator {
+    type_definitions: String,
+    methods: String,
+}
+
+impl RustClientGenerator {
+    // Get the Rust type for a field, emitting struct definitions for nested objects.
+    fn rust_type(&mut self, field_type: &FieldType, type_name: &str) -> String {
+        match field_type {
+            FieldType::Integer => "i64".to_string(),
+            FieldType::Number => "f64".to_string(),
+            FieldType::String => "String".to_string(),
+            FieldType::Bool => "bool".to_string(),
+            FieldType::Any => "serde_json::Value".to_string(),
+            FieldType::Array(element_type) => format!("Vec<{}>", self.rust_type(element_type, &format!("{}Item", type_name))),
+            FieldType::Nullable(inner_type) => format!("Option<{}>", self.rust_type(inner_type, type_name)),
+            FieldType::Object(field_types) if field_types.is_empty() => "serde_json::Map<String, serde_json::Value>".to_string(),
+            FieldType::Object(field_types) => {
+                // Fields named like Rust identifiers keep their names; the others are numbered when they collide.
+                let mut used_identifiers: HashSet<String> = field_types
+                    .keys()
+                    .map(|name| field_identifier(name))
+                    .filter_map(|(identifier, is_renamed)| (!is_renamed).then_some(identifier))
+                    .collect();
+                let mut fields = String::new();
+                for (name, field_type) in field_types {
+                    let field_rust_type = self.rust_type(field_type, &format!("{}{}", type_name, upper_camel_case(name)));
+                    let (mut identifier, is_renamed) = field_identifier(name);
+                    if is_renamed {
+                        identifier = unique_identifier(identifier, &mut used_identifiers);
+                        _ = writeln!(fields, "    #[serde(rename = {:?})]", name);
+                    }
+                    _ = writeln!(fields, "    pub {}: {},", identifier, field_rust_type);
+                }
+                _ = write!(
+                    self.type_definitions,
+                    "\n#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\npub struct {} {{\n{}}}\n",
+                    type_name, fields,
+                );
+                type_name.to_string()
+            },
+        }
+    }
+
+    fn add_endpoint(&mut self, endpoint: &EndpointRoute, operation_name: &str) {
+        let route_path = endpoint.route_path();
+
+        // Path parameters become arguments, and are interpolated into the URL.
+        let mut used_identifiers: HashSet<String> = HashSet::new();
+        let mut arguments: Vec<String> = vec!["&self".to_string()];
+        let mut path_format = String::new();
+        let mut path_arguments: Vec<String> = vec![];
+        for segment in &route_path.segments {
+            path_format.push('/');
+            match segment {
+                RouteSegment::Static(name) => path_format.push_str(&name.replace('{', "{{").replace('}', "}}")),
+                RouteSegment::Parameter(name) | RouteSegment::Wildcard(name) => {
+                    let (identifier, _) = field_identifier(name);
+                    let identifier = if identifier == REQUEST_BODY_ARGUMENT { format!("{}_", identifier) } else { identifier };
+                    let identifier = unique_identifier(identifier, &mut used_identifiers);
+                    arguments.push(format!("{}: impl std::fmt::Display", identifier));
+                    path_arguments.push(identifier);
+                    path_format.push_str("{}");
+                },
+            }
+        }
+        if path_format.is_empty() {
+            path_format.push('/');
+        }
+
+        if let Some(request_body) = &endpoint.request_body {
+            let request_type = self.rust_type(request_body, &format!("{}Request", operation_name));
+            arguments.push(format!("{}: &{}", REQUEST_BODY_ARGUMENT, request_type));
+        }
+        // Responses to HEAD requests have no body, whatever the endpoint declares.
+        let response = endpoint.response.as_ref().filter(|_| endpoint.method != HTTPMethod::Head);
+        let response_type = match response {
+            Some(response) => self.rust_type(response, &format!("{}Response", operation_name)),
+            None => "()".to_string(),
+        };
+
+        let method = match endpoint.method {
+            HTTPMethod::Get => "GET",
+            HTTPMethod::Patch => "PATCH",
+            HTTPMethod::Post => "POST",
+            HTTPMethod::Put => "PUT",
+            HTTPMethod::Delete => "DELETE",
+            HTTPMethod::Head => "HEAD",
+            HTTPMethod::Options => "OPTIONS",
+        };
+        let url_expression = if path_arguments.is_empty() {
+            format!("format!(\"{{}}{}\", self.base_url)", path_format)
+        } else {
+            format!("format!(\"{{}}{}\", self.base_url, {})", path_format, path_arguments.join(", "))
+        };
+        let request_call = format!("self.client.request(reqwest::Method::{}, {})", method, url_expression);
+        let body_call = if endpoint.request_body.is_some() {
+            format!("\n            .json({})", REQUEST_BODY_ARGUMENT)
+        } else {
+            String::new()
+        };
+        let response_handling = if response.is_some() {
+            "response.json().await"
+        } else {
+            "Ok(())"
+        };
+
+        _ = write!(
+            self.methods,
+            "
+    // {method_name} {route}
+    pub async fn {function_name}({arguments}) -> Result<{response_type}, reqwest::Error> {{
+        let response = {request_call}{body_call}
+            .send()
+            .await?
+            .error_for_status()?;
+        {response_handling}
+    }}
+",
+            method_name = format!("{:?}", endpoint.method).to_uppercase(),
+            route = endpoint.route,
+            function_name = snake_case(operation_name),
+            arguments = arguments.join(", "),
+        );
+    }
+}
+
+// Generate the `Cargo.toml` of the client crate.
+pub fn rust_client_manifest() -> String {
+    format!(
+        "[package]
+name = \"{}\"
+version = \"0.1.0\"
+edition = \"2024\"
+
+[dependencies]
+reqwest = {{ version = \"0.13.1\", features = [\"json\"] }}
+serde = {{ version = \"1.0.228\", features = [\"derive\"] }}
+serde_json = \"1.0.149\"
+",
+        RUST_CLIENT_CRATE_NAME,
+    )
+}
+
+// Generate the `src/lib.rs` of the client crate, with one async method per endpoint.
+pub fn rust_client_library(endpoints: &[EndpointRoute]) -> String {
+    let mut generator = RustClientGenerator {
+        type_definitions: String::new(),
+        methods: String::new(),
+    };
+    for (endpoint, operation_name) in endpoints.iter().zip(EndpointRoute::operation_names(endpoints)) {
+        generator.add_endpoint(endpoint, &operation_name);
+    }
+
+    format!(
+        "// Generated by rust-auto-gpt-coder from the backend endpoint schema. Do not edit.
+
+#![allow(clippy::all, unused_variables)]
+
+use serde::{{ Deserialize, Serialize }};
+{}
+#[derive(Debug, Clone)]
+pub struct BackendClient {{
+    base_url: String,
+    client: reqwest::Client,
+}}
+
+impl BackendClient {{
+    pub fn new(base_url: impl Into<String>) -> Self {{
+        Self::with_client(base_url, reqwest::Client::new())
+    }}
+
+    pub fn with_client(base_url: impl Into<String>, client: reqwest::Client) -> Self {{
+        let base_url: String = base_url.into();
+        Self {{
+            base_url: base_url.trim_end_matches('/').to_string(),
+            client,
+        }}
+    }}
+{}}}
+",
+        generator.type_definitions, generator.methods,
+    )
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::endpoint_route::HTTPMethod;
+    use crate::utilities::backend_code_persistence::BackendCodePersistence;
+    use crate::utilities::rest_api_analyzer::analyze_rest_api_endpoints;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_rust_client_for_code_template() {
+        let code_template = BackendCodePersistence::read_code_template_contents();
+        let endpoints = analyze_rest_api_endpoints(&code_template)
+            .expect("Failed to parse code template.")
+            .endpoints;
+
+        let library = rust_client_library(&endpoints);
+        syn::parse_file(&library).expect("Generated client is not valid Rust.");
+        assert!(library.contains("pub struct PostTaskRequest {"));
+        assert!(library.contains("    pub id: i64,"));
+        assert!(library.contains("pub async fn get_task(&self) -> Result<Vec<GetTaskResponseItem>, reqwest::Error>"));
+        assert!(library.contains("pub async fn get_task_id(&self, id: impl std::fmt::Display) -> Result<GetTaskIdResponse, reqwest::Error>"));
+        assert!(library.contains("pub async fn post_task(&self, body: &PostTaskRequest) -> Result<(), reqwest::Error>"));
+        assert!(library.contains("self.client.request(reqwest::Method::GET, format!(\"{}/task/{}\", self.base_url, id))"));
+    }
+
+    #[test]
+    fn test_rust_client_keyword_fields() {
+        let endpoint = EndpointRoute::new(
+            HTTPMethod::Get,
+            "/quote/{self}".to_string(),
+            None,
+            Some(FieldType::Object(BTreeMap::from([
+                ("crate".to_string(), FieldType::String),
+                ("price".to_string(), FieldType::Number),
+                ("type".to_string(), FieldType::String),
+            ]))),
+        );
+
+        let library = rust_client_library(&[endpoint]);
+        syn::parse_file(&library).expect("Generated client is not valid Rust.");
+        assert!(library.contains("    #[serde(rename = \"crate\")]\n    pub crate_: String,"));
+        assert!(library.contains("    pub price: f64,"));
+        assert!(library.contains("    pub r#type: String,"));
+        assert!(library.contains("pub async fn get_quote_self(&self, self_: impl std::fmt::Display)"));
+    }
+
+    #[test]
+    fn test_rust_client_body_path_parameter() {
+        let endpoints = [
+            EndpointRoute::new(
+                HTTPMethod::Put,
+                "/documents/{body}".to_string(),
+                Some(FieldType::Object(BTreeMap::from([
+                    ("text".to_string(), FieldType::String),
+                ]))),
+                None,
+            ),
+            EndpointRoute::new(HTTPMethod::Options, "/documents".to_string(), None, None),
+        ];
+
+        let library = rust_client_library(&endpoints);
+        syn::parse_file(&library).expect("Generated client is not valid Rust.");
+        assert!(library.contains("(&self, body_: impl std::fmt::Display, body: &PutDocumentsBodyRequest)"));
+        assert!(library.contains("format!(\"{}/documents/{}\", self.base_url, body_))\n            .json(body)"));
+        assert!(library.contains("self.client.request(reqwest::Method::OPTIONS, format!(\"{}/documents\", self.base_url))"));
+    }
+
+    #[test]
+    fn test_rust_client_head_endpoint() {
+        let endpoint = EndpointRoute::new(
+            HTTPMethod::Head,
+            "/task/{id}".to_string(),
+            None,
+            Some(FieldType::Object(BTreeMap::from([
+                ("id".to_string(), FieldType::Integer),
+            ]))),
+        );
+
+        let library = rust_client_library(&[endpoint]);
+        syn::parse_file(&library).expect("Generated client is not valid Rust.");
+        assert!(library.contains("pub async fn head_task_id(&self, id: impl std::fmt::Display) -> Result<(), reqwest::Error>"));
+        assert!(!library.contains("response.json()"));
+        assert!(!library.contains("HeadTaskIdResponse"));
+    }
+
+    #[test]
+    fn test_field_identifier() {
+        assert_eq!(field_identifier("name"), ("name".to_string(), false));
+        assert_eq!(field_identifier("type"), ("r#type".to_string(), false));
+        assert_eq!(field_identifier("crate"), ("crate_".to_string(), true));
+        assert_eq!(field_identifier("self"), ("self_".to_string(), true));
+        assert_eq!(field_identifier("super"), ("super_".to_string(), true));
+        assert_eq!(field_identifier("createdAt"), ("created_at".to_string(), true));
+        assert_eq!(field_identifier("2fa-code"), ("_2fa_code".to_string(), true));
+    }
+
+    #[test]
+    fn test_rust_client_colliding_fields() {
+        let endpoint = EndpointRoute::new(
+            HTTPMethod::Get,
+            "/event/{eventId}/{event_id}".to_string(),
+            None,
+            Some(FieldType::Object(BTreeMap::from([
+                ("created-at".to_string(), FieldType::String),
+                ("createdAt".to_string(), FieldType::String),
+                ("created_at".to_string(), FieldType::String),
+                ("createdat".to_string(), FieldType::String),
+            ]))),
+        );
+
+        let library = rust_client_library(&[endpoint]);
+        syn::parse_file(&library).expect("Generated client is not valid Rust.");
+        assert!(library.contains("    pub created_at: String,"));
+        assert!(library.contains("    #[serde(rename = \"created-at\")]\n    pub created_at_2: String,"));
+        assert!(library.contains("    #[serde(rename = \"createdAt\")]\n    pub created_at_3: String,"));
+        assert!(library.contains("    pub createdat: String,"));
+        assert!(library.contains("(&self, event_id: impl std::fmt::Display, event_id_2: impl std::fmt::Display)"));
+    }
+
+    #[test]
+    fn test_rust_client_colliding_operations() {
+        let endpoints = [
+            EndpointRoute::new(HTTPMethod::Get, "/task-id".to_string(), None, Some(FieldType::Integer)),
+            EndpointRoute::new(HTTPMethod::Get, "/task/{id}".to_string(), None, Some(FieldType::String)),
+        ];
+
+        let library = rust_client_library(&endpoints);
+        syn::parse_file(&library).expect("Generated client is not valid Rust.");
+        assert!(library.contains("pub async fn get_task_id(&self) -> Result<i64, reqwest::Error>"));
+        assert!(library.contains("pub async fn get_task_id2(&self, id: impl std::fmt::Display) -> Result<String, reqwest::Error>"));
+    }
+}
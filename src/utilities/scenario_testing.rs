@@ -0,0 +1,103 @@
+/*
+ *  utilities/scenario_testing.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::endpoint_testing::SYNTHESIZED_RESOURCE_ID;
+use super::networking::send_request;
+use crate::model::defect::Defect;
+use crate::model::test_scenario::{ TestScenario, TestScenarioStep };
+
+use reqwest::Client;
+use serde_json::Value;
+
+const IDENTIFIER_PLACEHOLDER: &str = "{id}";
+
+// Replace the identifier placeholder of a scenario path with the last identifier returned.
+fn fill_scenario_path(path: &str, resource_id: u64) -> String {
+    path.replace(IDENTIFIER_PLACEHOLDER, &resource_id.to_string())
+}
+
+fn defect_for_step(
+    scenario: &TestScenario,
+    step_index: usize,
+    step: &TestScenarioStep,
+    url: String,
+    actual_status_code: Option<u16>,
+    response_body: Option<String>,
+    description: String,
+) -> Defect {
+    Defect {
+        scenario: scenario.name.clone(),
+        step: Some(step_index),
+        method: Some(step.method.clone()),
+        url,
+        request_body: step.request_body.clone(),
+        expected_status_codes: step.expected_status_codes.clone(),
+        actual_status_code,
+        response_body,
+        description,
+    }
+}
+
+// Run a scenario against a live server, stopping at the first step that misbehaves since later steps depend on it.
+pub async fn run_test_scenario(client: &Client, base_url: &str, scenario: &TestScenario) -> Option<Defect> {
+    let mut resource_id = SYNTHESIZED_RESOURCE_ID;
+
+    for (step_index, step) in scenario.steps.iter().enumerate() {
+        let url = format!("{}{}", base_url, fill_scenario_path(&step.path, resource_id));
+        match send_request(client, &step.method, &url, step.request_body.as_ref()).await {
+            Ok((status_code, response_body)) => {
+                if !step.expected_status_codes.contains(&status_code) {
+                    let description = format!(
+                        "responded with status code {} instead of one of {:?}",
+                        status_code, step.expected_status_codes,
+                    );
+                    return Some(defect_for_step(scenario, step_index, step, url, Some(status_code), Some(response_body), description));
+                }
+
+                // Remember an ID assigned by the server for later steps.
+                if let Ok(response_json) = serde_json::from_str::<Value>(&response_body)
+                    && let Some(created_id) = response_json.get("id").and_then(Value::as_u64)
+                {
+                    resource_id = created_id;
+                }
+            },
+            Err(error) => {
+                let description = format!("request failed: {}", error);
+                return Some(defect_for_step(scenario, step_index, step, url, None, None, description));
+            },
+        }
+    }
+
+    None
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_scenarios_and_fill_paths() {
+        let scenarios: Vec<TestScenario> = serde_json::from_str(r#"[
+            {
+                "name": "Create and read a task",
+                "description": "A user creates a task, then reads it back.",
+                "steps": [
+                    { "method": "POST", "path": "/task", "request_body": { "id": 1, "name": "Groceries" }, "expected_status_codes": [200, 201] },
+                    { "method": "GET", "path": "/task/{id}", "expected_status_codes": [200] }
+                ]
+            }
+        ]"#).expect("Failed to decode test scenarios.");
+
+        assert_eq!(scenarios[0].steps.len(), 2);
+        assert_eq!(scenarios[0].steps[1].request_body, None);
+        assert_eq!(fill_scenario_path(&scenarios[0].steps[1].path, 7), "/task/7");
+    }
+}
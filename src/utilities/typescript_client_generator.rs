@@ -0,0 +1,248 @@
+/*
+ *  utilities/typescript_client_generator.rs
+ *  rust-auto-gpt-coder
+ *
+ *  Created by Joel Lopes Da Silva on 10/19/26.
+ *  Copyright © 2026 Joel Lopes Da Silva. All rights reserved.
+ *
+ */
+
+use super::code_naming::upper_camel_case;
+
+use crate::model::endpoint_route::EndpointRoute;
+use crate::model::field_type::FieldType;
+use crate::model::route_path::RouteSegment;
+
+use std::fmt::Write;
+
+// Words which cannot name a parameter in a strict-mode module.
+const TYPESCRIPT_RESERVED_WORDS: &[&str] = &[
+    "arguments", "await", "break", "case", "catch", "class", "const", "continue", "debugger",
+    "default", "delete", "do", "else", "enum", "eval", "export", "extends", "false", "finally",
+    "for", "function", "if", "implements", "import", "in", "instanceof", "interface", "let", "new",
+    "null", "package", "private", "protected", "public", "return", "static", "super", "switch",
+    "this", "throw", "true", "try", "typeof", "var", "void", "while", "with", "yield",
+];
+
+// Parameter holding the request body, which path parameters are renamed away from.
+const REQUEST_BODY_PARAMETER: &str = "body";
+
+const TYPESCRIPT_CLIENT_RUNTIME: &str = r#"
+export class ApiError extends Error {
+  constructor(public readonly status: number, public readonly body: string) {
+    super(`Request failed with status ${status}: ${body}`);
+  }
+}
+
+export class ApiClient {
+  private readonly baseUrl: string;
+
+  constructor(baseUrl: string, private readonly fetchImplementation: typeof fetch = fetch) {
+    this.baseUrl = baseUrl.replace(/\/+$/, "");
+  }
+
+  private async request(method: string, path: string, body?: unknown): Promise<Response> {
+    const response = await this.fetchImplementation(`${this.baseUrl}${path}`, {
+      method,
+      headers: body === undefined ? undefined : { "Content-Type": "application/json" },
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    if (!response.ok) {
+      throw new ApiError(response.status, await response.text());
+    }
+    return response;
+  }
+"#;
+
+// Convert a name into lowerCamelCase, as used for TypeScript methods and parameters.
+fn lower_camel_case(name: &str) -> String {
+    let mut characters = name.chars();
+    match characters.next() {
+        Some(first_character) => format!("{}{}", first_character.to_ascii_lowercase(), characters.as_str()),
+        None => String::new(),
+    }
+}
+
+// Quote property names which are not plain identifiers.
+fn property_name(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .enumerate()
+        .all(|(index, character)| character == '_' || character == '$' || character.is_ascii_alphabetic() || (index > 0 && character.is_ascii_digit()));
+    if is_identifier && !name.is_empty() {
+        name.to_string()
+    } else {
+        format!("{:?}", name)
+    }
+}
+
+// Get a parameter name which is a valid identifier, renaming reserved words and `body` with a trailing underscore.
+fn parameter_name(name: &str) -> String {
+    let name = lower_camel_case(&upper_camel_case(name));
+    match name.chars().next() {
+        Some(_) if TYPESCRIPT_RESERVED_WORDS.contains(&name.as_str()) || name == REQUEST_BODY_PARAMETER => format!("{}_", name),
+        Some(first_character) if first_character.is_ascii_alphabetic() => name,
+        _ => format!("_{}", name),
+    }
+}
+
+struct TypeScriptClientGenerator {
+    interfaces: String,
+    methods: String,
+}
+
+impl TypeScriptClientGenerator {
+    // Get the TypeScript type for a field, emitting interfaces for nested objects.
+    fn typescript_type(&mut self, field_type: &FieldType, type_name: &str) -> String {
+        match field_type {
+            FieldType::Integer | FieldType::Number => "number".to_string(),
+            FieldType::String => "string".to_string(),
+            FieldType::Bool => "boolean".to_string(),
+            FieldType::Any => "unknown".to_string(),
+            FieldType::Array(element_type) => format!("Array<{}>", self.typescript_type(element_type, &format!("{}Item", type_name))),
+            FieldType::Nullable(inner_type) => format!("{} | null", self.typescript_type(inner_type, type_name)),
+            FieldType::Object(field_types) if field_types.is_empty() => "Record<string, unknown>".to_string(),
+            FieldType::Object(field_types) => {
+                let mut properties = String::new();
+                for (name, field_type) in field_types {
+                    let property_type = self.typescript_type(field_type, &format!("{}{}", type_name, upper_camel_case(name)));
+                    _ = writeln!(properties, "  {}: {};", property_name(name), property_type);
+                }
+                _ = write!(self.interfaces, "\nexport interface {} {{\n{}}}\n", type_name, properties);
+                type_name.to_string()
+            },
+        }
+    }
+
+    fn add_endpoint(&mut self, endpoint: &EndpointRoute, operation_name: &str) {
+
+        // Path parameters become arguments, and are interpolated into the URL.
+        let mut parameters: Vec<String> = vec![];
+        let mut path_template = String::new();
+        for segment in &endpoint.route_path().segments {
+            path_template.push('/');
+            match segment {
+                RouteSegment::Static(name) => path_template.push_str(&name.replace('`', "\\`").replace("${", "\\${")),
+                RouteSegment::Parameter(name) => {
+                    let parameter = parameter_name(name);
+                    _ = write!(path_template, "${{encodeURIComponent(String({}))}}", parameter);
+                    parameters.push(format!("{}: string | number", parameter));
+                },
+                RouteSegment::Wildcard(name) => {
+                    let parameter = parameter_name(name);
+                    _ = write!(path_template, "${{encodeURI({})}}", parameter);
+                    parameters.push(format!("{}: string", parameter));
+                },
+            }
+        }
+        if path_template.is_empty() {
+            path_template.push('/');
+        }
+
+        let mut request_arguments = format!("{:?}, `{}`", format!("{:?}", endpoint.method).to_uppercase(), path_template);
+        if let Some(request_body) = &endpoint.request_body {
+            let request_type = self.typescript_type(request_body, &format!("{}Request", operation_name));
+            parameters.push(format!("{}: {}", REQUEST_BODY_PARAMETER, request_type));
+            _ = write!(request_arguments, ", {}", REQUEST_BODY_PARAMETER);
+        }
+        let (response_type, return_statement) = match &endpoint.response {
+            Some(response) => (
+                self.typescript_type(response, &format!("{}Response", operation_name)),
+                format!("return (await this.request({})).json();", request_arguments),
+            ),
+            None => ("void".to_string(), format!("await this.request({});", request_arguments)),
+        };
+
+        _ = write!(
+            self.methods,
+            "
+  // {method} {route}
+  async {function_name}({parameters}): Promise<{response_type}> {{
+    {return_statement}
+  }}
+",
+            method = format!("{:?}", endpoint.method).to_uppercase(),
+            route = endpoint.route,
+            function_name = lower_camel_case(operation_name),
+            parameters = parameters.join(", "),
+        );
+    }
+}
+
+// Generate a TypeScript module with interfaces for each body and a fetch wrapper per endpoint.
+pub fn typescript_client_module(endpoints: &[EndpointRoute]) -> String {
+    let mut generator = TypeScriptClientGenerator {
+        interfaces: String::new(),
+        methods: String::new(),
+    };
+    for (endpoint, operation_name) in endpoints.iter().zip(EndpointRoute::operation_names(endpoints)) {
+        generator.add_endpoint(endpoint, &operation_name);
+    }
+
+    format!(
+        "// Generated by rust-auto-gpt-coder from the backend endpoint schema. Do not edit.\n{}{}{}}}\n",
+        generator.interfaces, TYPESCRIPT_CLIENT_RUNTIME, generator.methods,
+    )
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::endpoint_route::HTTPMethod;
+    use crate::utilities::backend_code_persistence::BackendCodePersistence;
+    use crate::utilities::rest_api_analyzer::analyze_rest_api_endpoints;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_typescript_client_for_code_template() {
+        let code_template = BackendCodePersistence::read_code_template_contents();
+        let endpoints = analyze_rest_api_endpoints(&code_template)
+            .expect("Failed to parse code template.")
+            .endpoints;
+
+        let module = typescript_client_module(&endpoints);
+        assert!(module.contains("export interface PostTaskRequest {\n  completed: boolean;\n  id: number;\n  name: string;\n}"));
+        assert!(module.contains("async postTask(body: PostTaskRequest): Promise<void> {"));
+        assert!(module.contains("async getTask(): Promise<Array<GetTaskResponseItem>> {"));
+        assert!(module.contains("async getTaskId(id: string | number): Promise<GetTaskIdResponse> {\n    return (await this.request(\"GET\", `/task/${encodeURIComponent(String(id))}`)).json();"));
+    }
+
+    #[test]
+    fn test_typescript_reserved_parameter_names() {
+        let endpoint = EndpointRoute::new(
+            HTTPMethod::Delete,
+            "/class/{class}/default/{default}".to_string(),
+            None,
+            None,
+        );
+
+        let module = typescript_client_module(&[endpoint]);
+        assert!(module.contains("async deleteClassClassDefaultDefault(class_: string | number, default_: string | number): Promise<void> {"));
+        assert!(module.contains("`/class/${encodeURIComponent(String(class_))}/default/${encodeURIComponent(String(default_))}`"));
+        assert_eq!(parameter_name("task_id"), "taskId");
+        assert_eq!(parameter_name("delete"), "delete_");
+        assert_eq!(parameter_name("body"), "body_");
+    }
+
+    #[test]
+    fn test_typescript_client_nested_types() {
+        let endpoint = EndpointRoute::new(
+            HTTPMethod::Get,
+            "/files/{path:.*}".to_string(),
+            None,
+            Some(FieldType::Object(BTreeMap::from([
+                ("created-at".to_string(), FieldType::Nullable(Box::new(FieldType::String))),
+                ("owner".to_string(), FieldType::Object(BTreeMap::from([
+                    ("name".to_string(), FieldType::String),
+                ]))),
+            ]))),
+        );
+
+        let module = typescript_client_module(&[endpoint]);
+        assert!(module.contains("export interface GetFilesPathResponseOwner {\n  name: string;\n}"));
+        assert!(module.contains("  \"created-at\": string | null;\n  owner: GetFilesPathResponseOwner;"));
+        assert!(module.contains("async getFilesPath(path: string): Promise<GetFilesPathResponse> {\n    return (await this.request(\"GET\", `/files/${encodeURI(path)}`)).json();"));
+    }
+}